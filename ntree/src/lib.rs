@@ -3,6 +3,26 @@
 mod traversal;
 pub use traversal::*;
 
+mod newick;
+pub use newick::*;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+
+#[cfg(feature = "shared")]
+mod shared;
+#[cfg(feature = "shared")]
+pub use shared::*;
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque},
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    iter, mem,
+    ops::Not,
+};
+
 /// Asynchronous marker.
 pub struct Asynchronous;
 
@@ -26,6 +46,8 @@ macro_rules! node {
 #[derive(Debug)]
 pub struct Node<T> {
     pub value: T,
+    /// Children are always heap-allocated: storing them inline (e.g. in a `SmallVec`) would make
+    /// `Node` a recursive type of infinite size.
     pub children: Vec<Node<T>>,
 }
 
@@ -42,6 +64,11 @@ impl<T> Node<T> {
         self
     }
 
+    /// Returns a new node with the given value having the given trees as its children.
+    pub fn from_forest(value: T, roots: Vec<Node<T>>) -> Self {
+        Node::new(value).with_children(roots)
+    }
+
     /// Returns the number of descendants the node has. This method return 0 if, and only if,
     /// the node has no children.
     pub fn size(&self) -> usize {
@@ -62,87 +89,2813 @@ impl<T> Node<T> {
             .unwrap_or_default()
             .saturating_add(1)
     }
-}
 
-impl<T: Clone> Clone for Node<T> {
-    fn clone(&self) -> Self {
-        Self {
-            value: self.value.clone(),
-            children: self.children.clone(),
+    /// Returns the outline of the tree rooted by self, having one line per node in `pre-order`. Each
+    /// line consists of the given indent, repeated as many times as the depth of the node, followed
+    /// by the value of the node itself.
+    pub fn to_outline(&self, indent: &str) -> String
+    where
+        T: Display,
+    {
+        fn to_outline_immersion<T: Display>(
+            root: &Node<T>,
+            indent: &str,
+            depth: usize,
+            outline: &mut String,
+        ) {
+            outline.push_str(&indent.repeat(depth));
+            outline.push_str(&root.value.to_string());
+            outline.push('\n');
+
+            root.children
+                .iter()
+                .for_each(|child| to_outline_immersion(child, indent, depth + 1, outline));
         }
+
+        let mut outline = String::new();
+        to_outline_immersion(self, indent, 0, &mut outline);
+        outline
     }
-}
 
-impl<T: PartialEq> PartialEq for Node<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.value == other.value && self.children == other.children
+    /// Returns the number of root-to-leaf paths in the tree rooted by self for which the given
+    /// predicate returns true. The predicate is called with the values along each path, from the
+    /// root down to the leaf.
+    pub fn count_paths<P>(&self, predicate: P) -> usize
+    where
+        P: Fn(&[&T]) -> bool,
+    {
+        self.fold_paths(0, |count, path| count + predicate(path) as usize)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Calls the given closure once per root-to-leaf path in the tree rooted by self, providing the
+    /// values along the path, from the root down to the leaf, and the result of the previous call.
+    pub fn fold_paths<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &[&T]) -> B,
+    {
+        fn fold_paths_immersion<'a, T, B, F>(
+            root: &'a Node<T>,
+            path: &mut Vec<&'a T>,
+            acc: B,
+            f: &mut F,
+        ) -> B
+        where
+            F: FnMut(B, &[&T]) -> B,
+        {
+            path.push(&root.value);
+            let acc = if root.children.is_empty() {
+                f(acc, path)
+            } else {
+                root.children
+                    .iter()
+                    .fold(acc, |acc, child| fold_paths_immersion(child, path, acc, f))
+            };
 
-    #[test]
-    fn test_node_new() {
-        let root = Node::new(42);
-        assert_eq!(root.value, 42);
-        assert_eq!(root.children.len(), 0);
+            path.pop();
+            acc
+        }
+
+        fold_paths_immersion(self, &mut Vec::new(), init, &mut f)
     }
 
-    #[test]
-    fn test_node_value_mut() {
-        let mut root = Node::new(42);
-        assert_eq!(root.value, 42);
+    /// Traverses the tree rooted by self in `post-order`, calling the given closure along the way and
+    /// providing the value of each node together with the results of its children.
+    pub fn reduce_ref<R, F>(&self, mut f: F) -> R
+    where
+        F: FnMut(&T, &[R]) -> R,
+    {
+        fn reduce_ref_immersion<T, R, F>(root: &Node<T>, f: &mut F) -> R
+        where
+            F: FnMut(&T, &[R]) -> R,
+        {
+            let results: Vec<R> = root
+                .children
+                .iter()
+                .map(|child| reduce_ref_immersion(child, f))
+                .collect();
 
-        root.value = 123;
-        assert_eq!(root.value, 123);
+            f(&root.value, &results)
+        }
+
+        reduce_ref_immersion(self, &mut f)
     }
 
-    #[test]
-    fn test_node_add_child() {
-        let mut root = node!(10);
-        root.children.push(node!(20));
-        root.children.push(node!(30));
+    /// Removes and returns the first node, in `pre-order`, for which the given predicate returns true,
+    /// if any. The root of the tree is never considered, since it cannot be detached from a parent.
+    pub fn remove_first<P>(&mut self, mut predicate: P) -> Option<Node<T>>
+    where
+        P: FnMut(&Node<T>) -> bool,
+    {
+        fn remove_first_immersion<T, P>(root: &mut Node<T>, predicate: &mut P) -> Option<Node<T>>
+        where
+            P: FnMut(&Node<T>) -> bool,
+        {
+            for index in 0..root.children.len() {
+                if predicate(&root.children[index]) {
+                    return Some(root.children.remove(index));
+                }
 
-        assert_eq!(root.children.len(), 2);
+                if let Some(removed) = remove_first_immersion(&mut root.children[index], predicate)
+                {
+                    return Some(removed);
+                }
+            }
+
+            None
+        }
+
+        remove_first_immersion(self, &mut predicate)
     }
 
-    #[test]
-    fn test_node_children_mut() {
-        let mut root = node!(10, node!(20), node!(30));
-        root.children.swap(0, 1);
-        assert_eq!(root, node!(10, node!(30), node!(20)));
+    /// Returns the indices of the children of self in the order they would have if sorted by the
+    /// given comparator, leaving the tree untouched.
+    pub fn sorted_child_indices<F>(&self, mut compare: F) -> Vec<usize>
+    where
+        F: FnMut(&Node<T>, &Node<T>) -> Ordering,
+    {
+        let mut indices: Vec<usize> = (0..self.children.len()).collect();
+        indices.sort_by(|&a, &b| compare(&self.children[a], &self.children[b]));
+        indices
     }
 
-    #[test]
-    fn test_node_remove_child() {
-        let mut root = node!(10, node!(20), node!(30));
-        let removed = root.children.remove(0);
-        assert_eq!(removed.value, 20);
-        assert_eq!(root.children.len(), 1);
+    /// Returns the tree resulting from combining the values of self with the ones of the given
+    /// trees, or [None] if, and only if, any two of them do not share the same shape.
+    pub fn zip3<U, V>(self, b: Node<U>, c: Node<V>) -> Option<Node<(T, U, V)>> {
+        if self.children.len() != b.children.len() || self.children.len() != c.children.len() {
+            return None;
+        }
+
+        let children = self
+            .children
+            .into_iter()
+            .zip(b.children)
+            .zip(c.children)
+            .map(|((a, b), c)| a.zip3(b, c))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Node::new((self.value, b.value, c.value)).with_children(children))
     }
 
-    #[test]
-    fn test_node_size() {
-        let root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
-        assert_eq!(root.size(), 5);
+    /// Inserts the given child into the position that keeps the children of self sorted by the
+    /// given comparator, returning the index it has been inserted at. Children are assumed to be
+    /// already sorted by the same comparator, and equal children keep their insertion order.
+    pub fn insert_child_sorted<F>(&mut self, child: Node<T>, mut compare: F) -> usize
+    where
+        F: FnMut(&Node<T>, &Node<T>) -> Ordering,
+    {
+        let index = self
+            .children
+            .partition_point(|probe| compare(probe, &child) != Ordering::Greater);
+
+        self.children.insert(index, child);
+        index
     }
 
-    #[test]
-    fn test_node_height() {
-        let root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
-        assert_eq!(root.height(), 3);
+    /// Replaces every node at the given depth by its own children, which get spliced into the
+    /// children of its parent at the same position. Since the root has no parent, collapsing the
+    /// level 0 is a no-op.
+    pub fn collapse_level(&mut self, depth: usize) {
+        if depth == 0 {
+            return;
+        }
+
+        if depth == 1 {
+            self.children = std::mem::take(&mut self.children)
+                .into_iter()
+                .flat_map(|child| child.children)
+                .collect();
+
+            return;
+        }
+
+        self.children
+            .iter_mut()
+            .for_each(|child| child.collapse_level(depth - 1));
     }
 
-    #[test]
-    fn test_node_copy() {
-        let original = node!(10, node!(20), node!(30));
-        let mut copy = original.clone();
+    /// Returns a drawing of the tree rooted by self, having one line per node in `pre-order`. The
+    /// label of each node is given by the provided closure, so no trait bound is required on T.
+    pub fn debug_tree<F>(&self, fmt: F) -> String
+    where
+        F: Fn(&T) -> String,
+    {
+        fn debug_tree_immersion<T, F>(root: &Node<T>, prefix: &str, fmt: &F, tree: &mut String)
+        where
+            F: Fn(&T) -> String,
+        {
+            for (index, child) in root.children.iter().enumerate() {
+                let is_last = index + 1 == root.children.len();
+                let (branch, indent) = if is_last {
+                    ("└── ", "    ")
+                } else {
+                    ("├── ", "│   ")
+                };
 
-        assert_eq!(copy, original);
+                tree.push_str(prefix);
+                tree.push_str(branch);
+                tree.push_str(&fmt(&child.value));
+                tree.push('\n');
 
-        copy.children.remove(0);
-        assert_ne!(copy, original);
+                debug_tree_immersion(child, &format!("{prefix}{indent}"), fmt, tree);
+            }
+        }
+
+        let mut tree = fmt(&self.value);
+        tree.push('\n');
+
+        debug_tree_immersion(self, "", &fmt, &mut tree);
+        tree
+    }
+
+    /// Returns [Ok] containing self if the value of the root satisfies the given predicate, or
+    /// [Err] handing self back otherwise.
+    pub fn take_if<P>(self, predicate: P) -> Result<Node<T>, Node<T>>
+    where
+        P: Fn(&T) -> bool,
+    {
+        if predicate(&self.value) {
+            Ok(self)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Returns an iterator over the levels of the tree rooted by self, yielding all the nodes at the
+    /// same depth at once, from left to right. The first level consists of the root only.
+    pub fn levels(&self) -> impl Iterator<Item = Vec<&Node<T>>> {
+        iter::successors(Some(vec![self]), |level| {
+            let next: Vec<&Node<T>> = level.iter().flat_map(|node| &node.children).collect();
+            next.is_empty().not().then_some(next)
+        })
+    }
+
+    /// Calls the given closure on every value in the tree rooted by self that satisfies the given
+    /// predicate, leaving the rest untouched. Returns the number of transformed values.
+    pub fn map_if<F, P>(&mut self, predicate: P, f: F) -> usize
+    where
+        P: Fn(&T) -> bool,
+        F: Fn(&mut T),
+    {
+        let mut count = 0;
+        self.traverse_mut().for_each(|node| {
+            if predicate(&node.value) {
+                f(&mut node.value);
+                count += 1;
+            }
+        });
+
+        count
+    }
+
+    /// Binary searches the children of self for the given value, as [slice::binary_search] does.
+    /// Returns [Ok] with the index of a matching child, or [Err] with the index where a child with
+    /// such a value could be inserted keeping the children sorted. Children are assumed to be
+    /// already sorted by value.
+    pub fn binary_search_child(&self, value: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.children
+            .binary_search_by(|child| child.value.cmp(value))
+    }
+
+    /// Truncates the tree rooted by self so its height is no greater than the given one, removing
+    /// any node deeper than that. Since the root itself is always kept, capping the height to 0
+    /// behaves as capping it to 1, leaving the root as a leaf.
+    pub fn cap_height(&mut self, max_height: usize) {
+        if max_height <= 1 {
+            self.children.clear();
+            return;
+        }
+
+        self.children
+            .iter_mut()
+            .for_each(|child| child.cap_height(max_height - 1));
+    }
+
+    /// Returns the node at the given path of child indices, starting from self, if any.
+    fn node_at(&self, path: &[usize]) -> Option<&Node<T>> {
+        path.iter()
+            .try_fold(self, |node, &index| node.children.get(index))
+    }
+
+    /// Returns a mutable reference to the node at the given path of child indices, starting from
+    /// self, if any.
+    fn node_at_mut(&mut self, path: &[usize]) -> Option<&mut Node<T>> {
+        path.iter()
+            .try_fold(self, |node, &index| node.children.get_mut(index))
+    }
+
+    /// Returns the path of child indices leading from self to the first node, in `pre-order`, for
+    /// which the given predicate, called with the path and the node itself, returns true, if any.
+    fn position<'a, P>(&'a self, mut predicate: P) -> Option<Vec<usize>>
+    where
+        P: FnMut(&[usize], &'a Node<T>) -> bool,
+    {
+        fn position_immersion<'a, T, P>(
+            root: &'a Node<T>,
+            predicate: &mut P,
+            path: &mut Vec<usize>,
+        ) -> bool
+        where
+            P: FnMut(&[usize], &'a Node<T>) -> bool,
+        {
+            if predicate(path, root) {
+                return true;
+            }
+
+            for (index, child) in root.children.iter().enumerate() {
+                path.push(index);
+                if position_immersion(child, predicate, path) {
+                    return true;
+                }
+
+                path.pop();
+            }
+
+            false
+        }
+
+        let mut path = Vec::new();
+        position_immersion(self, &mut predicate, &mut path).then_some(path)
+    }
+
+    /// Returns the number of descendants of the node at the given path of child indices, or [None]
+    /// if there is no such a node. An empty path stands for self.
+    pub fn descendants_at(&self, path: &[usize]) -> Option<usize> {
+        self.node_at(path).map(Node::size)
+    }
+
+    /// Removes, at every level of the tree rooted by self, any child whose value does not satisfy
+    /// the given predicate. Removed subtrees are dropped as a whole, without inspecting them.
+    pub fn filter_values<P>(&mut self, predicate: P)
+    where
+        P: Fn(&T) -> bool + Copy,
+    {
+        self.children.retain(|child| predicate(&child.value));
+        self.children
+            .iter_mut()
+            .for_each(|child| child.filter_values(predicate));
+    }
+
+    /// Returns the path of child indices leading from self to the first node, in `pre-order`, whose
+    /// value equals the given one, if any. The root is addressed by an empty path.
+    pub fn search(&self, target: &T) -> Option<Vec<usize>>
+    where
+        T: PartialEq,
+    {
+        self.position(|_, node| &node.value == target)
+    }
+
+    /// Consumes the tree rooted by self, returning a map from the key of each value, as given by the
+    /// provided closure, to the value itself. On collisions, the latest value in `pre-order` wins.
+    pub fn into_map_by<K, F>(self, key: F) -> HashMap<K, T>
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        let mut map = HashMap::new();
+        for value in self.into_traverse().pre() {
+            map.insert(key(&value), value);
+        }
+
+        map
+    }
+
+    /// Returns the first value in the tree rooted by self, in `pre-order`, that equals a value
+    /// already visited before it, if any. Therefore, this method returns [None] if, and only if,
+    /// all the values in the tree are unique.
+    pub fn find_duplicate_values(&self) -> Option<&T>
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::new();
+        self.traverse()
+            .pre()
+            .iter()
+            .map(|node| &node.value)
+            .find(|value| !seen.insert(*value))
+    }
+
+    /// Builds a new tree by calling the given closure along the tree rooted by self, providing the
+    /// depth of each node, being 0 the depth of the root, together with its value.
+    pub fn map_with_depth<U, F>(&self, f: F) -> Node<U>
+    where
+        F: Fn(usize, &T) -> U,
+    {
+        fn map_with_depth_immersion<T, U, F>(root: &Node<T>, depth: usize, f: &F) -> Node<U>
+        where
+            F: Fn(usize, &T) -> U,
+        {
+            Node::new(f(depth, &root.value)).with_children(
+                root.children
+                    .iter()
+                    .map(|child| map_with_depth_immersion(child, depth + 1, f))
+                    .collect(),
+            )
+        }
+
+        map_with_depth_immersion(self, 0, &f)
+    }
+
+    /// Calls the given closure once per level of the tree rooted by self, providing all the nodes at
+    /// that depth. Returns the results indexed by depth, the one of the root first.
+    pub fn reduce_by_level<R, F>(&self, f: F) -> Vec<R>
+    where
+        F: Fn(&[&Node<T>]) -> R,
+    {
+        self.levels().map(|level| f(&level)).collect()
+    }
+
+    /// Returns true if, and only if, all the leaves in the tree rooted by self are at the same depth
+    /// and all the other nodes have the same number of children.
+    pub fn is_perfect(&self) -> bool {
+        let arity = self.children.len();
+        self.levels().all(|level| {
+            level.iter().all(|node| node.children.len() == arity)
+                || level.iter().all(|node| node.children.is_empty())
+        })
+    }
+
+    /// Returns true if, and only if, no node in the tree rooted by self has more children than the
+    /// given branching, and all the levels are full except, perhaps, the last one, which is filled
+    /// from left to right.
+    pub fn is_complete(&self, branching: usize) -> bool {
+        let mut gap = false;
+        self.levels().flatten().all(|node| {
+            let arity = node.children.len();
+            if arity > branching || (gap && arity > 0) {
+                return false;
+            }
+
+            gap |= arity < branching;
+            true
+        })
+    }
+
+    /// Swaps the children at the given indices of the node at the given path of child indices.
+    /// Returns false if, and only if, there is no such a node or any of the indices is out of bounds.
+    pub fn swap_siblings(&mut self, parent_path: &[usize], a: usize, b: usize) -> bool {
+        let Some(parent) = self.node_at_mut(parent_path) else {
+            return false;
+        };
+
+        if a >= parent.children.len() || b >= parent.children.len() {
+            return false;
+        }
+
+        parent.children.swap(a, b);
+        true
+    }
+
+    /// Returns the indices of the children of self satisfying the given predicate.
+    pub fn child_positions<P>(&self, predicate: P) -> Vec<usize>
+    where
+        P: Fn(&Node<T>) -> bool,
+    {
+        self.children
+            .iter()
+            .enumerate()
+            .filter_map(|(index, child)| predicate(child).then_some(index))
+            .collect()
+    }
+
+    /// Returns the values in the tree rooted by self, indexed in `pre-order`, together with the list
+    /// of `(parent, child)` edges between them, given by those same indices.
+    pub fn to_adjacency_list(&self) -> (Vec<&T>, Vec<(usize, usize)>) {
+        fn to_adjacency_list_immersion<'a, T>(
+            root: &'a Node<T>,
+            values: &mut Vec<&'a T>,
+            edges: &mut Vec<(usize, usize)>,
+        ) {
+            let parent = values.len();
+            values.push(&root.value);
+
+            for child in &root.children {
+                edges.push((parent, values.len()));
+                to_adjacency_list_immersion(child, values, edges);
+            }
+        }
+
+        let mut values = Vec::new();
+        let mut edges = Vec::new();
+        to_adjacency_list_immersion(self, &mut values, &mut edges);
+        (values, edges)
+    }
+
+    /// Returns a copy of every subtree rooted at depth `lo`, truncated so that none of them goes
+    /// deeper than depth `hi` in the tree rooted by self. An empty forest is returned if `hi` is
+    /// lower than `lo`.
+    pub fn slice_depths(&self, lo: usize, hi: usize) -> Vec<Node<T>>
+    where
+        T: Clone,
+    {
+        if hi < lo {
+            return Vec::new();
+        }
+
+        self.levels()
+            .nth(lo)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|node| {
+                let mut node = node.clone();
+                node.cap_height(hi - lo + 1);
+                node
+            })
+            .collect()
+    }
+
+    /// Calls the given closure on each child of self, providing its index as well.
+    pub fn children_mut_indexed<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut Node<T>),
+    {
+        self.children
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, child)| f(index, child));
+    }
+
+    /// Removes the leaf whose key, as given by the provided closure, is the first best one according
+    /// to the given criteria, returning its value. The root is never removed, even if it is a leaf.
+    fn remove_leaf_by_key<B, F>(&mut self, key: F, is_better: fn(&B, &B) -> bool) -> Option<T>
+    where
+        F: Fn(&T) -> B,
+    {
+        let mut best: Option<(B, Vec<usize>)> = None;
+        self.position(|path, node| {
+            if node.children.is_empty() {
+                let candidate = key(&node.value);
+                if best
+                    .as_ref()
+                    .is_none_or(|(key, _)| is_better(&candidate, key))
+                {
+                    best = Some((candidate, path.to_vec()));
+                }
+            }
+
+            false
+        });
+
+        let (_, path) = best?;
+        let (index, parent) = path.split_last()?;
+        Some(self.node_at_mut(parent)?.children.remove(*index).value)
+    }
+
+    /// Removes the leaf with the greatest key, as given by the provided closure, returning its value.
+    /// On ties, the first leaf in `pre-order` is removed. The root is never removed, even if it is
+    /// a leaf, in which case [None] is returned.
+    pub fn remove_extreme_leaf_by_key<B, F>(&mut self, key: F) -> Option<T>
+    where
+        B: Ord,
+        F: Fn(&T) -> B,
+    {
+        self.remove_leaf_by_key(key, |candidate, best| candidate > best)
+    }
+
+    /// Removes the leaf with the lowest key, as given by the provided closure, returning its value.
+    /// On ties, the first leaf in `pre-order` is removed. The root is never removed, even if it is
+    /// a leaf, in which case [None] is returned.
+    pub fn remove_min_leaf_by_key<B, F>(&mut self, key: F) -> Option<T>
+    where
+        B: Ord,
+        F: Fn(&T) -> B,
+    {
+        self.remove_leaf_by_key(key, |candidate, best| candidate < best)
+    }
+
+    /// Returns, for every node in the tree rooted by self having children, in `pre-order`, its path of
+    /// child indices together with the values of its children.
+    pub fn children_value_matrix(&self) -> Vec<(Vec<usize>, Vec<&T>)> {
+        let mut matrix = Vec::new();
+        self.position(|path, node| {
+            if !node.children.is_empty() {
+                let values = node.children.iter().map(|child| &child.value).collect();
+                matrix.push((path.to_vec(), values));
+            }
+
+            false
+        });
+
+        matrix
+    }
+
+    /// Returns the number of nodes in the tree rooted by self, other than the root itself, that
+    /// satisfy the given predicate. That is, the number of parent-child relationships whose child
+    /// does match.
+    pub fn count_children_where<P>(&self, predicate: P) -> usize
+    where
+        P: Fn(&Node<T>) -> bool + Copy,
+    {
+        self.children
+            .iter()
+            .map(|child| predicate(child) as usize + child.count_children_where(predicate))
+            .sum()
+    }
+
+    /// Consumes self, returning its value and its children apart.
+    pub fn into_parts(self) -> (T, Vec<Node<T>>) {
+        (self.value, self.children)
+    }
+
+    /// Builds a new tree by calling the given closure along the tree rooted by self in `pre-order`.
+    /// Those nodes for which the closure returns `Ok(None)` are pruned together with their
+    /// descendants, which are not visited, while the first error returned aborts the whole process.
+    pub fn filter_try_map<U, E, F>(self, mut f: F) -> Result<Option<Node<U>>, E>
+    where
+        F: FnMut(&T) -> Result<Option<U>, E>,
+    {
+        fn filter_try_map_immersion<T, U, E, F>(
+            root: Node<T>,
+            f: &mut F,
+        ) -> Result<Option<Node<U>>, E>
+        where
+            F: FnMut(&T) -> Result<Option<U>, E>,
+        {
+            let Some(value) = f(&root.value)? else {
+                return Ok(None);
+            };
+
+            let mut children = Vec::with_capacity(root.children.len());
+            for child in root.children {
+                children.extend(filter_try_map_immersion(child, f)?);
+            }
+
+            Ok(Some(Node::new(value).with_children(children)))
+        }
+
+        filter_try_map_immersion(self, &mut f)
+    }
+
+    /// Folds the levels of the tree rooted by self, from the deepest one up to the root, calling the
+    /// given closure with the result of the previous call and all the nodes at the current depth.
+    pub fn reduce_levels<R, F>(&self, init: R, mut f: F) -> R
+    where
+        F: FnMut(R, &[&Node<T>]) -> R,
+    {
+        let levels: Vec<Vec<&Node<T>>> = self.levels().collect();
+        levels.iter().rev().fold(init, |acc, level| f(acc, level))
+    }
+
+    /// Returns the values along the path from self down to each node at the given depth, in
+    /// `pre-order`. A depth of 0 yields a single path consisting of the root alone.
+    pub fn paths_to_depth(&self, depth: usize) -> Vec<Vec<&T>> {
+        fn paths_to_depth_immersion<'a, T>(
+            root: &'a Node<T>,
+            depth: usize,
+            path: &mut Vec<&'a T>,
+            paths: &mut Vec<Vec<&'a T>>,
+        ) {
+            path.push(&root.value);
+            if depth == 0 {
+                paths.push(path.clone());
+            } else {
+                root.children
+                    .iter()
+                    .for_each(|child| paths_to_depth_immersion(child, depth - 1, path, paths));
+            }
+
+            path.pop();
+        }
+
+        let mut paths = Vec::new();
+        paths_to_depth_immersion(self, depth, &mut Vec::new(), &mut paths);
+        paths
+    }
+
+    /// Swaps the values of the nodes at the given paths of child indices, leaving their subtrees in
+    /// place. Returns false if, and only if, any of the paths does not address a node. Since only
+    /// values are exchanged, one node may be an ancestor of the other.
+    pub fn swap_values(&mut self, a: &[usize], b: &[usize]) -> bool {
+        let common = a.iter().zip(b).take_while(|(a, b)| a == b).count();
+        let Some(ancestor) = self.node_at_mut(&a[..common]) else {
+            return false;
+        };
+
+        match (a.get(common), b.get(common)) {
+            (None, None) => true,
+            (Some(&i), Some(&j)) => {
+                let (lo, hi) = (i.min(j), i.max(j));
+                if hi >= ancestor.children.len() {
+                    return false;
+                }
+
+                let (left, right) = ancestor.children.split_at_mut(hi);
+                let (node_a, node_b) = if i < j {
+                    (&mut left[lo], &mut right[0])
+                } else {
+                    (&mut right[0], &mut left[lo])
+                };
+
+                let (Some(node_a), Some(node_b)) = (
+                    node_a.node_at_mut(&a[common + 1..]),
+                    node_b.node_at_mut(&b[common + 1..]),
+                ) else {
+                    return false;
+                };
+
+                mem::swap(&mut node_a.value, &mut node_b.value);
+                true
+            }
+            _ => {
+                let rest = if a.len() > common {
+                    &a[common..]
+                } else {
+                    &b[common..]
+                };
+                let Node { value, children } = ancestor;
+                let Some(descendant) = children
+                    .get_mut(rest[0])
+                    .and_then(|child| child.node_at_mut(&rest[1..]))
+                else {
+                    return false;
+                };
+
+                mem::swap(value, &mut descendant.value);
+                true
+            }
+        }
+    }
+
+    /// Returns a height-balanced binary tree holding the given values, which are assumed to be
+    /// sorted, or [None] if there are no values at all. The middle value becomes the root, while the
+    /// values before and after it build up, recursively, its first and second child, respectively.
+    /// Empty halves produce no child, so a node with a single child does not tell which side it is.
+    pub fn balanced_bst_from_sorted(mut values: Vec<T>) -> Option<Node<T>> {
+        if values.is_empty() {
+            return None;
+        }
+
+        let mut greater = values.split_off(values.len() / 2);
+        let value = greater.remove(0);
+        let children = [values, greater]
+            .into_iter()
+            .filter_map(Node::balanced_bst_from_sorted)
+            .collect();
+
+        Some(Node::new(value).with_children(children))
+    }
+
+    /// Removes, from the tree rooted by self, any child whose [Node::size] is strictly less than the
+    /// given minimum. Since the size of a node counts its descendants only, a leaf is removed for
+    /// any minimum greater than zero. Subtrees are evaluated top-down, meaning the size of a child
+    /// is computed before pruning any of its own descendants.
+    pub fn prune_small(&mut self, min_size: usize) {
+        self.children.retain(|child| child.size() >= min_size);
+        self.children
+            .iter_mut()
+            .for_each(|child| child.prune_small(min_size));
+    }
+
+    /// Calls the given closure for each node in the tree rooted by self, in breadth-first order.
+    /// The closure may mutate the children of the node it receives: since the children of a node
+    /// are enqueued right after the closure returns, any child added by the closure is visited as
+    /// well, while removed children are not.
+    pub fn bfs_for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Node<T>),
+    {
+        let mut queue = VecDeque::from([Vec::new()]);
+        while let Some(path) = queue.pop_front() {
+            let Some(node) = self.node_at_mut(&path) else {
+                continue;
+            };
+
+            f(node);
+            queue.extend((0..node.children.len()).map(|index| {
+                let mut child = path.clone();
+                child.push(index);
+                child
+            }));
+        }
+    }
+
+    /// Moves all the children of the given node to the end of the children of self, leaving the
+    /// former as a leaf. Both nodes must be borrowed mutably at the same time, so siblings have to be
+    /// split apart first (e.g. by [slice::split_at_mut]).
+    pub fn adopt_children(&mut self, other: &mut Node<T>) {
+        self.children.append(&mut other.children);
+    }
+
+    /// Returns the number of distinct values in the tree rooted by self.
+    pub fn unique_value_count(&self) -> usize
+    where
+        T: Hash + Eq,
+    {
+        self.traverse()
+            .pre()
+            .iter()
+            .map(|node| &node.value)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Calls the given closure for each node in the tree rooted by self, in `post-order`, providing
+    /// its depth, being 0 the depth of the root, its value and the results of its children.
+    pub fn reduce_with_depth<R, F>(&self, mut f: F) -> R
+    where
+        F: FnMut(usize, &T, Vec<R>) -> R,
+    {
+        fn reduce_with_depth_immersion<T, R, F>(root: &Node<T>, depth: usize, f: &mut F) -> R
+        where
+            F: FnMut(usize, &T, Vec<R>) -> R,
+        {
+            let results = root
+                .children
+                .iter()
+                .map(|child| reduce_with_depth_immersion(child, depth + 1, f))
+                .collect();
+
+            f(depth, &root.value, results)
+        }
+
+        reduce_with_depth_immersion(self, 0, &mut f)
+    }
+
+    /// Keeps the first n nodes of the tree rooted by self, in breadth-first order, removing all the
+    /// others. The root always counts as the first node, and it is kept even if n is zero.
+    pub fn keep_first_n_bfs(&mut self, n: usize) {
+        let mut enqueued = 1_usize;
+        self.bfs_for_each_mut(|node| {
+            let kept = node.children.len().min(n.saturating_sub(enqueued));
+            node.children.truncate(kept);
+            enqueued += kept;
+        });
+    }
+
+    /// Returns an iterator over the values in the tree rooted by self, in breadth-first order.
+    pub fn bfs_values(&self) -> impl Iterator<Item = &T> {
+        self.traverse().level().map(|node| &node.value)
+    }
+
+    /// Removes the first node, in `pre-order`, whose value satisfies the given predicate, moving its
+    /// children into its parent at the position it was. Returns the value of the removed node, if
+    /// any. The root of the tree is never considered, since it has no parent to take its children.
+    pub fn splice_out_first<P>(&mut self, predicate: P) -> Option<T>
+    where
+        P: Fn(&T) -> bool,
+    {
+        fn splice_out_first_immersion<T, P>(root: &mut Node<T>, predicate: &P) -> Option<T>
+        where
+            P: Fn(&T) -> bool,
+        {
+            for index in 0..root.children.len() {
+                if predicate(&root.children[index].value) {
+                    let removed = root.children.remove(index);
+                    root.children.splice(index..index, removed.children);
+                    return Some(removed.value);
+                }
+
+                if let Some(removed) =
+                    splice_out_first_immersion(&mut root.children[index], predicate)
+                {
+                    return Some(removed);
+                }
+            }
+
+            None
+        }
+
+        splice_out_first_immersion(self, &predicate)
+    }
+
+    /// Returns the number of edges in the tree rooted by self for which the given predicate, being
+    /// provided with the values of the parent and the child, returns true.
+    pub fn count_edges<P>(&self, predicate: P) -> usize
+    where
+        P: Fn(&T, &T) -> bool,
+    {
+        fn count_edges_immersion<T, P>(root: &Node<T>, predicate: &P) -> usize
+        where
+            P: Fn(&T, &T) -> bool,
+        {
+            root.children.iter().fold(0, |count, child| {
+                count
+                    + usize::from(predicate(&root.value, &child.value))
+                    + count_edges_immersion(child, predicate)
+            })
+        }
+
+        count_edges_immersion(self, &predicate)
+    }
+
+    /// Returns how many times each distinct subtree occurs in the tree rooted by self, including the
+    /// whole tree itself. Subtrees are keyed by their representation in the Newick format, without
+    /// the trailing semicolon. Since labels are quoted whenever they could be mistaken for the
+    /// structure of the tree, two subtrees share a key if, and only if, they have the same shape and
+    /// displayed values, with children in the same order.
+    pub fn subtree_frequencies(&self) -> HashMap<String, usize>
+    where
+        T: Display,
+    {
+        let mut frequencies = HashMap::new();
+        self.reduce_ref(|value, keys: &[String]| {
+            let label = newick::newick_label(value);
+            let key = if keys.is_empty() {
+                label
+            } else {
+                format!("({}){label}", keys.join(","))
+            };
+
+            *frequencies.entry(key.clone()).or_default() += 1;
+            key
+        });
+
+        frequencies
+    }
+
+    /// Limits the number of children of every node in the tree rooted by self to the given maximum.
+    /// The exceeding children of a node are replaced by a single leaf, whose value is the result of
+    /// calling the given closure with the amount of children removed.
+    pub fn summarize_breadth<F>(&mut self, max: usize, make_ellipsis: F)
+    where
+        F: Fn(usize) -> T,
+    {
+        fn summarize_breadth_immersion<T, F>(root: &mut Node<T>, max: usize, make_ellipsis: &F)
+        where
+            F: Fn(usize) -> T,
+        {
+            let remaining = root.children.len().saturating_sub(max);
+            root.children.truncate(max);
+            root.children
+                .iter_mut()
+                .for_each(|child| summarize_breadth_immersion(child, max, make_ellipsis));
+
+            if remaining > 0 {
+                root.children.push(Node::new(make_ellipsis(remaining)));
+            }
+        }
+
+        summarize_breadth_immersion(self, max, &make_ellipsis)
+    }
+
+    /// Returns the values of the ancestors of the node at the given path of child indices, from self
+    /// down to the parent of the addressed node, or [None] if there is no such a node. Unlike
+    /// `path_values`, the addressed node is excluded, so the result is empty for the path
+    /// addressing self.
+    pub fn ancestor_values(&self, path: &[usize]) -> Option<Vec<&T>> {
+        let mut ancestors = Vec::with_capacity(path.len());
+        path.iter()
+            .try_fold(self, |node, &index| {
+                ancestors.push(&node.value);
+                node.children.get(index)
+            })
+            .map(|_| ancestors)
+    }
+
+    /// Removes, for every node in the tree rooted by self, those children whose key, as returned by
+    /// the given closure, equals the key of any previous sibling. Therefore, only the first child
+    /// per key is kept.
+    pub fn dedup_children_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Hash + Eq,
+        F: FnMut(&Node<T>) -> K,
+    {
+        fn dedup_children_by_key_immersion<T, K, F>(root: &mut Node<T>, key: &mut F)
+        where
+            K: Hash + Eq,
+            F: FnMut(&Node<T>) -> K,
+        {
+            let mut seen = HashSet::new();
+            root.children.retain(|child| seen.insert(key(child)));
+            root.children
+                .iter_mut()
+                .for_each(|child| dedup_children_by_key_immersion(child, key));
+        }
+
+        dedup_children_by_key_immersion(self, &mut key)
+    }
+
+    /// Calls the given closure for each node in the tree rooted by self, in `pre-order`, providing a
+    /// mutable reference to an accumulator starting at the given initial state. Returns the results
+    /// of all the calls, in the same order.
+    pub fn scan<A, R, F>(&self, init: A, mut f: F) -> Vec<R>
+    where
+        F: FnMut(&mut A, &Node<T>) -> R,
+    {
+        let mut state = init;
+        self.traverse()
+            .pre()
+            .iter()
+            .map(|node| f(&mut state, node))
+            .collect()
+    }
+
+    /// Returns a 64-bit fingerprint of the tree rooted by self, combining the hash of each value with
+    /// the fingerprints of its children. Equal trees always have the same fingerprint, but it is
+    /// only guaranteed to be stable within the same build.
+    pub fn structural_hash(&self) -> u64
+    where
+        T: Hash,
+    {
+        self.reduce_ref(|value, hashes: &[u64]| {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hashes.hash(&mut hasher);
+            hasher.finish()
+        })
+    }
+
+    /// Builds a new tree by calling the given closure along the tree rooted by self, providing the
+    /// value of each node together with its original children.
+    pub fn map_ref_with_children<U, F>(&self, f: F) -> Node<U>
+    where
+        F: Fn(&T, &[Node<T>]) -> U,
+    {
+        fn map_ref_with_children_immersion<T, U, F>(root: &Node<T>, f: &F) -> Node<U>
+        where
+            F: Fn(&T, &[Node<T>]) -> U,
+        {
+            Node::new(f(&root.value, &root.children)).with_children(
+                root.children
+                    .iter()
+                    .map(|child| map_ref_with_children_immersion(child, f))
+                    .collect(),
+            )
+        }
+
+        map_ref_with_children_immersion(self, &f)
+    }
+
+    /// Returns all the nodes at the given depth in the tree rooted by self, from left to right, being
+    /// 0 the depth of the root. The result is empty if the tree is not that deep.
+    pub fn nodes_at_depth(&self, depth: usize) -> Vec<&Node<T>> {
+        self.levels().nth(depth).unwrap_or_default()
+    }
+
+    /// Appends, to each node in the tree rooted by self, the subtrees returned by the given closure
+    /// for that node. The closure is only called on the nodes existing before the expansion, so the
+    /// appended subtrees are never expanded themselves. Returns the total number of nodes added.
+    pub fn expand<F>(&mut self, f: F) -> usize
+    where
+        F: Fn(&Node<T>) -> Vec<Node<T>> + Copy,
+    {
+        let mut grafted = f(self);
+        let mut added = self
+            .children
+            .iter_mut()
+            .map(|child| child.expand(f))
+            .sum::<usize>();
+
+        added += grafted.iter().map(|node| node.size() + 1).sum::<usize>();
+        self.children.append(&mut grafted);
+        added
+    }
+
+    /// Returns the node resulting from removing all the children of self.
+    pub fn into_leaf(mut self) -> Node<T> {
+        self.make_leaf();
+        self
+    }
+
+    /// Removes all the children of self, making it a leaf.
+    pub fn make_leaf(&mut self) {
+        self.children.clear();
+    }
+
+    /// Returns the number of nodes in the tree rooted by self having exactly the given number of
+    /// children. Therefore, a degree of 0 counts the leaves of the tree.
+    pub fn count_nodes_with_degree(&self, degree: usize) -> usize {
+        self.traverse()
+            .pre()
+            .iter()
+            .filter(|node| node.children.len() == degree)
+            .count()
+    }
+
+    /// Consumes the tree rooted by self, calling the map closure on each value in `pre-order` and the
+    /// reduce closure in `post-order`, providing the mapped value of the node together with the
+    /// results of its children. Returns the result of the root.
+    pub fn into_map_reduce<M, R, FM, FR>(self, mut map: FM, mut reduce: FR) -> R
+    where
+        FM: FnMut(T) -> M,
+        FR: FnMut(M, Vec<R>) -> R,
+    {
+        fn into_map_reduce_immersion<T, M, R, FM, FR>(
+            root: Node<T>,
+            map: &mut FM,
+            reduce: &mut FR,
+        ) -> R
+        where
+            FM: FnMut(T) -> M,
+            FR: FnMut(M, Vec<R>) -> R,
+        {
+            let mapped = map(root.value);
+            let results = root
+                .children
+                .into_iter()
+                .map(|child| into_map_reduce_immersion(child, map, reduce))
+                .collect();
+
+            reduce(mapped, results)
+        }
+
+        into_map_reduce_immersion(self, &mut map, &mut reduce)
+    }
+
+    /// Calls the given closure for each node in the tree rooted by self, in `post-order`, providing
+    /// the results of its children. Nodes at the given maximum depth, being 0 the depth of the root,
+    /// are not traversed any further, but summarized by the cutoff closure instead.
+    pub fn reduce_bounded<R, F, G>(&self, max_depth: usize, mut f: F, cutoff: G) -> R
+    where
+        F: FnMut(&Node<T>, Vec<R>) -> R,
+        G: Fn(&Node<T>) -> R,
+    {
+        fn reduce_bounded_immersion<T, R, F, G>(
+            root: &Node<T>,
+            depth: usize,
+            max_depth: usize,
+            f: &mut F,
+            cutoff: &G,
+        ) -> R
+        where
+            F: FnMut(&Node<T>, Vec<R>) -> R,
+            G: Fn(&Node<T>) -> R,
+        {
+            if depth >= max_depth {
+                return cutoff(root);
+            }
+
+            let results = root
+                .children
+                .iter()
+                .map(|child| reduce_bounded_immersion(child, depth + 1, max_depth, f, cutoff))
+                .collect();
+
+            f(root, results)
+        }
+
+        reduce_bounded_immersion(self, 0, max_depth, &mut f, &cutoff)
+    }
+
+    /// Returns the values of the tree rooted by self in `pre-order`, but visiting the children of
+    /// each node from right to left.
+    pub fn preorder_values_rev(&self) -> Vec<&T> {
+        let mut values = Vec::new();
+        let mut next = vec![self];
+        while let Some(node) = next.pop() {
+            values.push(&node.value);
+            next.extend(node.children.iter());
+        }
+
+        values
+    }
+
+    /// Returns a compact representation of the tree rooted by self, where the value of each node is
+    /// followed by its children, if any, separated by the given separator and wrapped between the
+    /// given open and close characters.
+    pub fn to_bracketed(&self, open: char, close: char, sep: char) -> String
+    where
+        T: Display,
+    {
+        fn to_bracketed_immersion<T: Display>(
+            root: &Node<T>,
+            delimiters: (char, char, char),
+            bracketed: &mut String,
+        ) {
+            let (open, close, sep) = delimiters;
+            bracketed.push_str(&root.value.to_string());
+            if root.children.is_empty() {
+                return;
+            }
+
+            bracketed.push(open);
+            for (index, child) in root.children.iter().enumerate() {
+                if index > 0 {
+                    bracketed.push(sep);
+                }
+
+                to_bracketed_immersion(child, delimiters, bracketed);
+            }
+
+            bracketed.push(close);
+        }
+
+        let mut bracketed = String::new();
+        to_bracketed_immersion(self, (open, close, sep), &mut bracketed);
+        bracketed
+    }
+
+    /// Returns a tree with the same shape as the one rooted by self, where each value is paired with
+    /// the number of children of its node.
+    pub fn with_degrees(&self) -> Node<(usize, &T)> {
+        Node::new((self.children.len(), &self.value))
+            .with_children(self.children.iter().map(Node::with_degrees).collect())
+    }
+
+    /// Consumes self, dropping its value, and returns its children.
+    pub fn into_children(self) -> Vec<Node<T>> {
+        self.children
+    }
+
+    /// Returns all the nodes in the tree rooted by self, in `pre-order`, for which the given predicate
+    /// returns true.
+    pub fn find_all<P>(&self, predicate: P) -> Vec<&Node<T>>
+    where
+        P: Fn(&Node<T>) -> bool,
+    {
+        self.traverse()
+            .pre()
+            .iter()
+            .filter(|node| predicate(node))
+            .collect()
+    }
+
+    /// Builds a new tree by calling the given closure along the tree rooted by self, providing the
+    /// depth of each node, being 0 the depth of the root, its index among its siblings, being 0 the
+    /// index of the root, and its value, in that order.
+    pub fn map_with_level_info<U, F>(&self, f: F) -> Node<U>
+    where
+        F: Fn(usize, usize, &T) -> U,
+    {
+        fn map_with_level_info_immersion<T, U, F>(
+            root: &Node<T>,
+            depth: usize,
+            index: usize,
+            f: &F,
+        ) -> Node<U>
+        where
+            F: Fn(usize, usize, &T) -> U,
+        {
+            Node::new(f(depth, index, &root.value)).with_children(
+                root.children
+                    .iter()
+                    .enumerate()
+                    .map(|(index, child)| map_with_level_info_immersion(child, depth + 1, index, f))
+                    .collect(),
+            )
+        }
+
+        map_with_level_info_immersion(self, 0, 0, &f)
+    }
+
+    /// Returns the values along the longest branch in the tree rooted by self, from the root down to
+    /// the deepest leaf. Ties are resolved in favor of the leftmost child.
+    pub fn longest_branch(&self) -> Vec<&T> {
+        iter::successors(Some(self), |node| {
+            node.children
+                .iter()
+                .rev()
+                .max_by_key(|child| child.height())
+        })
+        .map(|node| &node.value)
+        .collect()
+    }
+
+    /// Keeps the children of self for which the given predicate returns true, removing and returning
+    /// the rest in their original order. Only the direct children of self are considered.
+    pub fn partition_children<P>(&mut self, predicate: P) -> Vec<Node<T>>
+    where
+        P: FnMut(&Node<T>) -> bool,
+    {
+        let (kept, removed) = mem::take(&mut self.children)
+            .into_iter()
+            .partition(predicate);
+
+        self.children = kept;
+        removed
+    }
+
+    /// Removes from the tree rooted by self all the branches not leading to any of its n deepest
+    /// leaves. Leaves at the same depth are ranked in `pre-order`. The root is always kept, even if
+    /// n is zero, in which case it becomes a leaf itself.
+    pub fn keep_deepest_leaves(&mut self, n: usize) {
+        fn retain_immersion<T>(
+            root: &mut Node<T>,
+            path: &mut Vec<usize>,
+            kept: &HashSet<Vec<usize>>,
+        ) {
+            root.children = mem::take(&mut root.children)
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, mut child)| {
+                    path.push(index);
+                    let keep = kept.contains(path);
+                    if keep {
+                        retain_immersion(&mut child, path, kept);
+                    }
+
+                    path.pop();
+                    keep.then_some(child)
+                })
+                .collect();
+        }
+
+        let mut leaves = Vec::new();
+        self.position(|path, node| {
+            if node.children.is_empty() {
+                leaves.push(path.to_vec());
+            }
+
+            false
+        });
+
+        leaves.sort_by_key(|path| Reverse(path.len()));
+
+        let kept: HashSet<Vec<usize>> = leaves
+            .into_iter()
+            .take(n)
+            .flat_map(|path| (1..=path.len()).map(move |len| path[..len].to_vec()))
+            .collect();
+
+        retain_immersion(self, &mut Vec::new(), &kept);
+    }
+
+    /// Returns an iterator over the nodes of the tree rooted by self in `post-order`, each paired with
+    /// its depth, being 0 the depth of the root.
+    pub fn iter_postorder_with_depth(&self) -> impl Iterator<Item = (usize, &Node<T>)> {
+        let mut stack = vec![(0, self, 0)];
+        iter::from_fn(move || loop {
+            let (depth, node, next_child) = stack.last_mut()?;
+            if let Some(child) = node.children.get(*next_child) {
+                *next_child += 1;
+                let depth = *depth + 1;
+                stack.push((depth, child, 0));
+            } else {
+                let (depth, node, _) = stack.pop()?;
+                return Some((depth, node));
+            }
+        })
+    }
+
+    /// Builds a tree out of a list of `(parent, child)` edges. The index 0 stands for the root, having
+    /// the given root value, while any other index i stands for the node having the value at
+    /// position i - 1 in the given vector. Children are added in the same order as their edges.
+    /// Notice this is off by one with respect to `to_adjacency_list`, where the index 0 is the first
+    /// of the values: its output is rebuilt by passing the first value as root and the rest of them
+    /// as the given vector.
+    /// Returns [None] if the edges do not form a single tree connecting all the nodes: that is, if
+    /// any node other than the root does not have exactly one parent, or if there is any cycle.
+    pub fn from_edges(root: T, edges: &[(usize, usize)], values: Vec<T>) -> Option<Node<T>> {
+        fn from_edges_immersion<T>(
+            index: usize,
+            children: &[Vec<usize>],
+            values: &mut [Option<T>],
+        ) -> Option<Node<T>> {
+            let value = values[index].take()?;
+            let children = children[index]
+                .iter()
+                .map(|&child| from_edges_immersion(child, children, values))
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(Node::new(value).with_children(children))
+        }
+
+        let mut values: Vec<Option<T>> = iter::once(root).chain(values).map(Some).collect();
+        let mut children = vec![Vec::new(); values.len()];
+        let mut has_parent = vec![false; values.len()];
+
+        for &(parent, child) in edges {
+            if parent >= values.len() || child >= values.len() || child == 0 || has_parent[child] {
+                return None;
+            }
+
+            has_parent[child] = true;
+            children[parent].push(child);
+        }
+
+        if has_parent.iter().skip(1).any(|has_parent| !has_parent) {
+            return None;
+        }
+
+        // Nodes in a cycle have a parent but are unreachable from the root, so they are left behind.
+        let tree = from_edges_immersion(0, &children, &mut values)?;
+        values.iter().all(Option::is_none).then_some(tree)
+    }
+
+    /// Returns the number of pairs of siblings, adjacent or not, in the tree rooted by self that are
+    /// out of order by the given key. That is, the number of siblings whose key is greater than the
+    /// key of any sibling to their right, summed across all the nodes.
+    pub fn child_inversions<B, F>(&self, key: F) -> usize
+    where
+        B: Ord,
+        F: Fn(&Node<T>) -> B + Copy,
+    {
+        let keys: Vec<B> = self.children.iter().map(key).collect();
+        let inversions = keys
+            .iter()
+            .enumerate()
+            .map(|(index, current)| {
+                keys[index + 1..]
+                    .iter()
+                    .filter(|&next| current > next)
+                    .count()
+            })
+            .sum::<usize>();
+
+        self.children.iter().fold(inversions, |count, child| {
+            count + child.child_inversions(key)
+        })
+    }
+
+    /// Returns the rendering of the tree rooted by self, built in `post-order` by calling the given
+    /// closure on each value together with the renderings of its children.
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(&T, &[String]) -> String,
+    {
+        self.reduce_ref(f)
+    }
+
+    /// Calls the given closure for each value in the tree rooted by self, in breadth-first order.
+    pub fn bfs_values_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.bfs_for_each_mut(|node| f(&mut node.value));
+    }
+
+    /// Removes, for every node in the tree rooted by self, those children equal, by value and shape,
+    /// to any previous sibling. Descendants are deduplicated first, so subtrees that only become
+    /// equal after removing their own duplicates are deduplicated as well.
+    pub fn dedup_identical_children(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.children
+            .iter_mut()
+            .for_each(Node::dedup_identical_children);
+
+        let mut kept = Vec::with_capacity(self.children.len());
+        for child in mem::take(&mut self.children) {
+            if !kept.contains(&child) {
+                kept.push(child);
+            }
+        }
+
+        self.children = kept;
+    }
+
+    /// Folds the direct children of self, from left to right, by calling the given closure on each
+    /// of them. Descendants other than the children are not visited.
+    pub fn fold_children<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &Node<T>) -> B,
+    {
+        self.children.iter().fold(init, f)
+    }
+
+    /// Panics if any node in the tree rooted by self does not satisfy the given predicate, reporting
+    /// the path of child indices and the value of the first one found in `pre-order`. This check is
+    /// only performed in debug builds, being a no-op otherwise.
+    pub fn debug_assert_all<P>(&self, predicate: P)
+    where
+        P: Fn(&Node<T>) -> bool,
+        T: Debug,
+    {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        let violation = self
+            .position(|_, node| !predicate(node))
+            .and_then(|path| Some((self.node_at(&path)?, path)));
+
+        if let Some((node, path)) = violation {
+            panic!(
+                "node at path {path:?} with value {:?} does not satisfy the predicate",
+                node.value
+            );
+        }
+    }
+
+    /// Returns the children of self having the minimum and the maximum key, in that order, or [None]
+    /// if self is a leaf. On ties, the leftmost minimum and the rightmost maximum are returned, as
+    /// [Iterator::min_by_key] and [Iterator::max_by_key] do.
+    pub fn child_extremes_by_key<B, F>(&self, key: F) -> Option<(&Node<T>, &Node<T>)>
+    where
+        B: Ord,
+        F: Fn(&Node<T>) -> B + Copy,
+    {
+        let min = self.children.iter().min_by_key(|child| key(child))?;
+        let max = self.children.iter().max_by_key(|child| key(child))?;
+        Some((min, max))
+    }
+}
+
+impl<T> Node<Option<T>> {
+    /// Returns the tree resulting from unwrapping all the values in the tree rooted by self, or
+    /// [None] if, and only if, any of them is [None].
+    pub fn transpose(self) -> Option<Node<T>> {
+        let children = self
+            .children
+            .into_iter()
+            .map(Node::transpose)
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Node::new(self.value?).with_children(children))
+    }
+}
+
+impl<T, E> Node<Result<T, E>> {
+    /// Returns the tree resulting from unwrapping all the values in the tree rooted by self, or the
+    /// first error found in `pre-order`, if any.
+    pub fn collect_result(self) -> Result<Node<T>, E> {
+        let value = self.value?;
+        let children = self
+            .children
+            .into_iter()
+            .map(Node::collect_result)
+            .collect::<Result<Vec<_>, E>>()?;
+
+        Ok(Node::new(value).with_children(children))
+    }
+
+    /// Returns the tree resulting from calling the given closure on each error in the tree rooted by
+    /// self, leaving the successful values untouched.
+    pub fn map_err<F, E2>(self, f: F) -> Node<Result<T, E2>>
+    where
+        F: Fn(E) -> E2 + Copy,
+    {
+        Node::new(self.value.map_err(f)).with_children(
+            self.children
+                .into_iter()
+                .map(|child| child.map_err(f))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Clone> Clone for Node<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Node<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.children == other.children
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_new() {
+        let root = Node::new(42);
+        assert_eq!(root.value, 42);
+        assert_eq!(root.children.len(), 0);
+    }
+
+    #[test]
+    fn test_node_value_mut() {
+        let mut root = Node::new(42);
+        assert_eq!(root.value, 42);
+
+        root.value = 123;
+        assert_eq!(root.value, 123);
+    }
+
+    #[test]
+    fn test_node_add_child() {
+        let mut root = node!(10);
+        root.children.push(node!(20));
+        root.children.push(node!(30));
+
+        assert_eq!(root.children.len(), 2);
+    }
+
+    #[test]
+    fn test_node_children_mut() {
+        let mut root = node!(10, node!(20), node!(30));
+        root.children.swap(0, 1);
+        assert_eq!(root, node!(10, node!(30), node!(20)));
+    }
+
+    #[test]
+    fn test_node_remove_child() {
+        let mut root = node!(10, node!(20), node!(30));
+        let removed = root.children.remove(0);
+        assert_eq!(removed.value, 20);
+        assert_eq!(root.children.len(), 1);
+    }
+
+    #[test]
+    fn test_node_size() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
+        assert_eq!(root.size(), 5);
+    }
+
+    #[test]
+    fn test_node_height() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
+        assert_eq!(root.height(), 3);
+    }
+
+    #[test]
+    fn test_node_copy() {
+        let original = node!(10, node!(20), node!(30));
+        let mut copy = original.clone();
+
+        assert_eq!(copy, original);
+
+        copy.children.remove(0);
+        assert_ne!(copy, original);
+    }
+
+    #[test]
+    fn test_node_to_outline() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
+        assert_eq!(
+            root.to_outline("  "),
+            "10\n  20\n    40\n  30\n    50\n    60\n"
+        );
+    }
+
+    #[test]
+    fn test_node_count_paths() {
+        let root = node!(1, node!(2, node!(3)), node!(4, node!(5), node!(6)));
+        let even_paths = root.count_paths(|path| path.iter().copied().sum::<i32>() % 2 == 0);
+        assert_eq!(even_paths, 2);
+    }
+
+    #[test]
+    fn test_node_reduce_ref() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50, node!(60))));
+        let depth = root
+            .reduce_ref(|_, depths: &[usize]| depths.iter().max().copied().unwrap_or_default() + 1);
+
+        assert_eq!(depth, 4);
+        assert_eq!(depth, root.height());
+    }
+
+    #[test]
+    fn test_node_remove_first() {
+        let mut root = node!(2, node!(3, node!(4, node!(6))), node!(8));
+        let removed = root.remove_first(|n| n.value % 2 == 0);
+
+        assert_eq!(removed, Some(node!(4, node!(6))));
+        assert_eq!(root, node!(2, node!(3), node!(8)));
+
+        let mut root = node!(1, node!(3));
+        assert_eq!(root.remove_first(|n| n.value % 2 == 0), None);
+        assert_eq!(root, node!(1, node!(3)));
+    }
+
+    #[test]
+    fn test_node_sorted_child_indices() {
+        let root = node!(10, node!(3), node!(1), node!(2));
+        let indices = root.sorted_child_indices(|a, b| a.value.cmp(&b.value));
+
+        assert_eq!(indices, vec![1, 2, 0]);
+        assert_eq!(root, node!(10, node!(3), node!(1), node!(2)));
+    }
+
+    #[test]
+    fn test_node_transpose() {
+        let root = node!(Some(10), node!(Some(20), node!(Some(40))), node!(Some(30)));
+        assert_eq!(
+            root.transpose(),
+            Some(node!(10, node!(20, node!(40)), node!(30)))
+        );
+
+        let root = node!(Some(10), node!(Some(20), node!(None)), node!(Some(30)));
+        assert_eq!(root.transpose(), None);
+    }
+
+    #[test]
+    fn test_node_collect_result() {
+        let root: Node<Result<i32, &str>> =
+            node!(Ok(10), node!(Ok(20), node!(Ok(40))), node!(Ok(30)));
+        assert_eq!(
+            root.collect_result(),
+            Ok(node!(10, node!(20, node!(40)), node!(30)))
+        );
+
+        let root: Node<Result<i32, &str>> = node!(
+            Ok(10),
+            node!(Ok(20), node!(Err("first"))),
+            node!(Err("second"))
+        );
+        assert_eq!(root.collect_result(), Err("first"));
+    }
+
+    #[test]
+    fn test_node_zip3() {
+        let a = node!(1, node!(2, node!(4)), node!(3));
+        let b = node!('a', node!('b', node!('d')), node!('c'));
+        let c = node!(true, node!(false, node!(true)), node!(false));
+
+        let want = node!(
+            (1, 'a', true),
+            node!((2, 'b', false), node!((4, 'd', true))),
+            node!((3, 'c', false))
+        );
+
+        assert_eq!(a.clone().zip3(b.clone(), c), Some(want));
+        assert_eq!(a.zip3(b, node!(true, node!(false), node!(false))), None);
+    }
+
+    #[test]
+    fn test_node_insert_child_sorted() {
+        let mut root = node!(10, node!(1), node!(3), node!(5));
+
+        let index = root.insert_child_sorted(node!(4), |a, b| a.value.cmp(&b.value));
+        assert_eq!(index, 2);
+
+        let index = root.insert_child_sorted(node!(0), |a, b| a.value.cmp(&b.value));
+        assert_eq!(index, 0);
+
+        assert_eq!(
+            root,
+            node!(10, node!(0), node!(1), node!(3), node!(4), node!(5))
+        );
+    }
+
+    #[test]
+    fn test_node_collapse_level() {
+        let mut root = node!(
+            10,
+            node!(20, node!(40), node!(50)),
+            node!(30, node!(60, node!(70)))
+        );
+        root.collapse_level(0);
+        assert_eq!(root.height(), 4);
+
+        root.collapse_level(1);
+        assert_eq!(root, node!(10, node!(40), node!(50), node!(60, node!(70))));
+    }
+
+    #[test]
+    fn test_node_debug_tree() {
+        struct Item {
+            name: &'static str,
+        }
+
+        let root = node!(
+            Item { name: "root" },
+            node!(Item { name: "a" }, node!(Item { name: "c" })),
+            node!(Item { name: "b" })
+        );
+
+        assert_eq!(
+            root.debug_tree(|item| item.name.to_string()),
+            "root\n├── a\n│   └── c\n└── b\n"
+        );
+    }
+
+    #[test]
+    fn test_node_take_if() {
+        let root = node!(10, node!(20), node!(30));
+        assert_eq!(
+            root.clone().take_if(|value| value % 2 == 0),
+            Ok(root.clone())
+        );
+
+        assert_eq!(root.clone().take_if(|value| value % 2 != 0), Err(root));
+    }
+
+    #[test]
+    fn test_node_levels() {
+        let root = node!(
+            10,
+            node!(20, node!(40, node!(70))),
+            node!(30, node!(50), node!(60))
+        );
+        let levels: Vec<Vec<i32>> = root
+            .levels()
+            .map(|level| level.iter().map(|n| n.value).collect())
+            .collect();
+
+        assert_eq!(
+            levels,
+            vec![vec![10], vec![20, 30], vec![40, 50, 60], vec![70]]
+        );
+    }
+
+    #[test]
+    fn test_node_map_if() {
+        let mut root = node!(10, node!(21, node!(40)), node!(30, node!(51)));
+        let count = root.map_if(|value| value % 2 == 0, |value| *value = -*value);
+
+        assert_eq!(count, 3);
+        assert_eq!(
+            root,
+            node!(-10, node!(21, node!(-40)), node!(-30, node!(51)))
+        );
+    }
+
+    #[test]
+    fn test_node_binary_search_child() {
+        let root = node!(10, node!(1), node!(3), node!(5));
+        assert_eq!(root.binary_search_child(&3), Ok(1));
+        assert_eq!(root.binary_search_child(&4), Err(2));
+        assert_eq!(root.binary_search_child(&0), Err(0));
+        assert_eq!(root.binary_search_child(&6), Err(3));
+    }
+
+    #[test]
+    fn test_node_from_forest() {
+        let roots = vec![
+            node!(20, node!(40)),
+            node!(30, node!(50), node!(60)),
+            node!(70),
+        ];
+
+        let size = roots.iter().map(Node::size).sum::<usize>() + roots.len();
+        let root = Node::from_forest(10, roots);
+
+        assert_eq!(root.size(), size);
+        assert_eq!(root.children.len(), 3);
+    }
+
+    #[test]
+    fn test_node_cap_height() {
+        let mut root = node!(10, node!(20, node!(40, node!(70))), node!(30, node!(50)));
+        assert_eq!(root.height(), 4);
+
+        root.cap_height(2);
+        assert_eq!(root.height(), 2);
+        assert_eq!(root, node!(10, node!(20), node!(30)));
+
+        root.cap_height(0);
+        assert_eq!(root, node!(10));
+    }
+
+    #[test]
+    fn test_node_descendants_at() {
+        let root = node!(10, node!(20, node!(40), node!(50)), node!(30, node!(60)));
+        assert_eq!(root.descendants_at(&[]), Some(root.size()));
+        assert_eq!(root.descendants_at(&[0]), Some(root.children[0].size()));
+        assert_eq!(root.descendants_at(&[1, 0]), Some(0));
+        assert_eq!(root.descendants_at(&[2]), None);
+    }
+
+    #[test]
+    fn test_node_filter_values() {
+        let mut root = node!(
+            10,
+            node!(20, node!(41), node!(60, node!(81))),
+            node!(31, node!(50), node!(70))
+        );
+
+        root.filter_values(|value| value % 2 == 0);
+        assert_eq!(root, node!(10, node!(20, node!(60))));
+    }
+
+    #[test]
+    fn test_node_search() {
+        let root = node!(
+            10,
+            node!(20, node!(40)),
+            node!(30, node!(50, node!(60)), node!(60))
+        );
+        assert_eq!(root.search(&10), Some(vec![]));
+        assert_eq!(root.search(&60), Some(vec![1, 0, 0]));
+        assert_eq!(root.search(&70), None);
+    }
+
+    #[test]
+    fn test_node_into_map_by() {
+        let root = node!(10, node!(21, node!(40)), node!(33));
+        let map = root.into_map_by(|value| value % 10);
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&0], 40);
+        assert_eq!(map[&1], 21);
+        assert_eq!(map[&3], 33);
+    }
+
+    #[test]
+    fn test_node_find_duplicate_values() {
+        let root = node!(10, node!(20, node!(30)), node!(30, node!(20)));
+        assert_eq!(root.find_duplicate_values(), Some(&30));
+
+        let root = node!(10, node!(20, node!(40)), node!(30));
+        assert_eq!(root.find_duplicate_values(), None);
+    }
+
+    #[test]
+    fn test_node_map_with_depth() {
+        let root = node!(10, node!(20, node!(40, node!(70))), node!(30));
+        let depths = root.map_with_depth(|depth, _| depth);
+
+        assert_eq!(depths, node!(0, node!(1, node!(2, node!(3))), node!(1)));
+        assert_eq!(depths.children[0].children[0].children[0].value, 3);
+    }
+
+    #[test]
+    fn test_node_reduce_by_level() {
+        let root = node!(
+            10,
+            node!(20, node!(40, node!(70))),
+            node!(30, node!(50), node!(60))
+        );
+        let sums = root.reduce_by_level(|level| level.iter().map(|n| n.value).sum::<i32>());
+        assert_eq!(sums, vec![10, 50, 150, 70]);
+    }
+
+    #[test]
+    fn test_node_is_perfect() {
+        let root = node!(
+            1,
+            node!(2, node!(4), node!(5)),
+            node!(3, node!(6), node!(7))
+        );
+        assert!(root.is_perfect());
+
+        let root = node!(1, node!(2, node!(4), node!(5)), node!(3));
+        assert!(!root.is_perfect());
+    }
+
+    #[test]
+    fn test_node_is_complete() {
+        let root = node!(
+            1,
+            node!(2, node!(4), node!(5)),
+            node!(3, node!(6), node!(7))
+        );
+        assert!(root.is_complete(2));
+
+        let root = node!(1, node!(2, node!(4), node!(5)), node!(3, node!(6)));
+        assert!(root.is_complete(2));
+        assert!(!root.is_complete(1));
+
+        let root = node!(1, node!(2), node!(3, node!(6), node!(7)));
+        assert!(!root.is_complete(2));
+    }
+
+    #[test]
+    fn test_node_swap_siblings() {
+        let mut root = node!(10, node!(20, node!(40), node!(50), node!(60)), node!(30));
+        assert!(root.swap_siblings(&[0], 0, 2));
+        assert_eq!(
+            root,
+            node!(10, node!(20, node!(60), node!(50), node!(40)), node!(30))
+        );
+
+        assert!(!root.swap_siblings(&[0], 0, 3));
+        assert!(!root.swap_siblings(&[2], 0, 1));
+    }
+
+    #[test]
+    fn test_node_child_positions() {
+        let root = node!(10, node!(20, node!(41)), node!(31), node!(40), node!(51));
+        assert_eq!(root.child_positions(|n| n.value % 2 == 0), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_node_to_adjacency_list() {
+        let root = node!(10, node!(20, node!(40), node!(50)), node!(30));
+        let (values, edges) = root.to_adjacency_list();
+
+        assert_eq!(values, vec![&10, &20, &40, &50, &30]);
+        assert_eq!(edges, vec![(0, 1), (1, 2), (1, 3), (0, 4)]);
+        assert_eq!(edges.len(), root.size());
+        assert!(edges
+            .iter()
+            .all(|&(parent, child)| parent < child && child < values.len()));
+    }
+
+    #[test]
+    fn test_node_slice_depths() {
+        let root = node!(
+            10,
+            node!(20, node!(40, node!(70))),
+            node!(30, node!(50), node!(60, node!(80)))
+        );
+
+        assert_eq!(root.height(), 4);
+        assert_eq!(
+            root.slice_depths(1, 2),
+            vec![node!(20, node!(40)), node!(30, node!(50), node!(60))]
+        );
+
+        assert!(root.slice_depths(2, 1).is_empty());
+        assert!(root.slice_depths(4, 5).is_empty());
+    }
+
+    #[test]
+    fn test_node_fold_paths() {
+        let root = node!(
+            1,
+            node!(2, node!(3)),
+            node!(4, node!(5, node!(7)), node!(6))
+        );
+        let total = root.fold_paths(0, |total, path| total + path.len());
+        assert_eq!(total, 3 + 4 + 3);
+
+        let paths = root.fold_paths(Vec::new(), |mut paths, path| {
+            paths.push(path.iter().copied().copied().collect::<Vec<_>>());
+            paths
+        });
+
+        assert_eq!(paths, vec![vec![1, 2, 3], vec![1, 4, 5, 7], vec![1, 4, 6]]);
+    }
+
+    #[test]
+    fn test_node_children_mut_indexed() {
+        let mut root = node!(10, node!(20, node!(40)), node!(30), node!(50));
+        root.children_mut_indexed(|index, child| child.value = index);
+        assert_eq!(root, node!(10, node!(0, node!(40)), node!(1), node!(2)));
+    }
+
+    #[test]
+    fn test_node_remove_extreme_leaf_by_key() {
+        let mut root = node!(10, node!(20, node!(70), node!(50)), node!(60));
+        assert_eq!(root.remove_extreme_leaf_by_key(|value| *value), Some(70));
+        assert_eq!(root.remove_extreme_leaf_by_key(|value| *value), Some(60));
+        assert_eq!(root, node!(10, node!(20, node!(50))));
+
+        let mut root = node!(10);
+        assert_eq!(root.remove_extreme_leaf_by_key(|value| *value), None);
+    }
+
+    #[test]
+    fn test_node_remove_min_leaf_by_key() {
+        let mut root = node!(10, node!(20, node!(70), node!(50)), node!(60));
+        assert_eq!(root.remove_min_leaf_by_key(|value| *value), Some(50));
+        assert_eq!(root.remove_min_leaf_by_key(|value| *value), Some(60));
+        assert_eq!(root, node!(10, node!(20, node!(70))));
+    }
+
+    #[test]
+    fn test_node_children_value_matrix() {
+        let root = node!(10, node!(20, node!(40), node!(50)), node!(30, node!(60)));
+        assert_eq!(
+            root.children_value_matrix(),
+            vec![
+                (vec![], vec![&20, &30]),
+                (vec![0], vec![&40, &50]),
+                (vec![1], vec![&60]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_node_count_children_where() {
+        let root = node!(
+            10,
+            node!(20, node!(40), node!(50)),
+            node!(30, node!(60, node!(70)))
+        );
+        let leaves = root
+            .traverse()
+            .pre()
+            .iter()
+            .filter(|n| n.children.is_empty())
+            .count();
+
+        assert_eq!(root.count_children_where(|n| n.children.is_empty()), 3);
+        assert_eq!(root.count_children_where(|n| n.children.is_empty()), leaves);
+        assert_eq!(node!(10).count_children_where(|n| n.children.is_empty()), 0);
+    }
+
+    #[test]
+    fn test_node_into_parts() {
+        let root = node!(10, node!(20, node!(40)), node!(30));
+        let (value, children) = root.clone().into_parts();
+
+        assert_eq!(value, 10);
+        assert_eq!(children, vec![node!(20, node!(40)), node!(30)]);
+        assert_eq!(Node::new(value).with_children(children), root);
+    }
+
+    #[test]
+    fn test_node_filter_try_map() {
+        let f = |value: &i32| match value {
+            value if *value < 0 => Err(*value),
+            value if value % 2 != 0 => Ok(None),
+            value => Ok(Some(value / 10)),
+        };
+
+        let root = node!(10, node!(20, node!(40)), node!(31, node!(50)));
+        assert_eq!(
+            root.filter_try_map(f),
+            Ok(Some(node!(1, node!(2, node!(4)))))
+        );
+
+        let root = node!(11, node!(20, node!(40)), node!(-30));
+        assert_eq!(root.filter_try_map(f), Ok(None));
+
+        let root = node!(10, node!(20, node!(-40)), node!(31));
+        assert_eq!(root.filter_try_map(f), Err(-40));
+    }
+
+    #[test]
+    fn test_node_reduce_levels() {
+        let root = node!(
+            10,
+            node!(20, node!(40, node!(70))),
+            node!(30, node!(50), node!(60))
+        );
+
+        let mut depths = Vec::new();
+        let sum = root.reduce_levels(0, |sum, level| {
+            depths.push(level.len());
+            sum + level.iter().map(|n| n.value).sum::<i32>()
+        });
+
+        assert_eq!(depths, vec![1, 3, 2, 1]);
+        assert_eq!(sum, root.traverse().pre().iter().map(|n| n.value).sum());
+    }
+
+    #[test]
+    fn test_node_paths_to_depth() {
+        let root = node!(10, node!(20, node!(40), node!(50)), node!(30, node!(60)));
+        assert_eq!(
+            root.paths_to_depth(2),
+            vec![
+                vec![&10, &20, &40],
+                vec![&10, &20, &50],
+                vec![&10, &30, &60]
+            ]
+        );
+
+        assert_eq!(root.paths_to_depth(0), vec![vec![&10]]);
+        assert!(root.paths_to_depth(3).is_empty());
+    }
+
+    #[test]
+    fn test_node_swap_values() {
+        let mut root = node!(10, node!(20, node!(40, node!(70))), node!(30, node!(50)));
+
+        assert!(root.swap_values(&[], &[0, 0, 0]));
+        assert_eq!(
+            root,
+            node!(70, node!(20, node!(40, node!(10))), node!(30, node!(50)))
+        );
+
+        assert!(root.swap_values(&[1, 0], &[0, 0]));
+        assert_eq!(
+            root,
+            node!(70, node!(20, node!(50, node!(10))), node!(30, node!(40)))
+        );
+
+        assert!(root.swap_values(&[1], &[1]));
+        assert!(!root.swap_values(&[1], &[2]));
+        assert!(!root.swap_values(&[0, 1], &[]));
+    }
+
+    #[test]
+    fn test_node_balanced_bst_from_sorted() {
+        let root = Node::balanced_bst_from_sorted(vec![1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(root.value, 3);
+        assert_eq!(root, node!(3, node!(2, node!(1)), node!(5, node!(4))));
+        assert!(root.traverse().pre().iter().all(|n| n.children.len() <= 2));
+
+        let heights: Vec<usize> = root.children.iter().map(Node::height).collect();
+        assert_eq!(heights, vec![2, 2]);
+
+        assert_eq!(Node::<i32>::balanced_bst_from_sorted(vec![]), None);
+    }
+
+    #[test]
+    fn test_node_prune_small() {
+        let mut root = node!(
+            1,
+            node!(2, node!(4), node!(5)),
+            node!(3, node!(6)),
+            node!(7)
+        );
+
+        root.prune_small(2);
+        assert_eq!(root, node!(1, node!(2)));
+
+        let mut root = node!(1, node!(2));
+        root.prune_small(0);
+        assert_eq!(root, node!(1, node!(2)));
+    }
+
+    #[test]
+    fn test_node_bfs_for_each_mut() {
+        let mut root = node!(0, node!(0, node!(0), node!(0)), node!(0, node!(0)));
+
+        let mut index = 0;
+        root.bfs_for_each_mut(|n| {
+            n.value = index;
+            index += 1;
+        });
+
+        assert_eq!(
+            root,
+            node!(0, node!(1, node!(3), node!(4)), node!(2, node!(5)))
+        );
+
+        let mut root = node!(0, node!(1));
+        let mut visited = Vec::new();
+        root.bfs_for_each_mut(|n| {
+            visited.push(n.value);
+            if n.value == 1 {
+                n.children.push(node!(2));
+            }
+        });
+
+        assert_eq!(visited, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_node_adopt_children() {
+        let mut parent = node!(1, node!(2));
+        let mut other = node!(3, node!(4), node!(5));
+
+        parent.adopt_children(&mut other);
+        assert_eq!(parent, node!(1, node!(2), node!(4), node!(5)));
+        assert_eq!(other, node!(3));
+
+        let mut root = node!(1, node!(2, node!(4)), node!(3, node!(5), node!(6)));
+        let (left, right) = root.children.split_at_mut(1);
+        left[0].adopt_children(&mut right[0]);
+
+        assert_eq!(
+            root,
+            node!(1, node!(2, node!(4), node!(5), node!(6)), node!(3))
+        );
+    }
+
+    #[test]
+    fn test_node_unique_value_count() {
+        let root = node!(1, node!(2, node!(1), node!(3)), node!(2, node!(3)));
+        assert_eq!(root.unique_value_count(), 3);
+        assert!(root.unique_value_count() < root.size() + 1);
+
+        assert_eq!(node!(1).unique_value_count(), 1);
+    }
+
+    #[test]
+    fn test_node_reduce_with_depth() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
+
+        let sum = root.reduce_with_depth(|depth, value, results: Vec<usize>| {
+            value * depth + results.into_iter().sum::<usize>()
+        });
+
+        assert_eq!(sum, 20 + 30 + (40 + 50 + 60) * 2);
+    }
+
+    #[test]
+    fn test_node_keep_first_n_bfs() {
+        let root = node!(1, node!(2, node!(4), node!(5)), node!(3, node!(6)));
+
+        let mut got = root.clone();
+        got.keep_first_n_bfs(3);
+        assert_eq!(got, node!(1, node!(2), node!(3)));
+
+        let mut got = root.clone();
+        got.keep_first_n_bfs(4);
+        assert_eq!(got, node!(1, node!(2, node!(4)), node!(3)));
+        assert_eq!(got.size() + 1, 4);
+
+        let mut got = root.clone();
+        got.keep_first_n_bfs(0);
+        assert_eq!(got, node!(1));
+
+        let mut got = root.clone();
+        got.keep_first_n_bfs(10);
+        assert_eq!(got, root);
+    }
+
+    #[test]
+    fn test_node_bfs_values() {
+        let root = node!(10, node!(20, node!(40)), node!(30));
+        let got: Vec<_> = root.bfs_values().copied().collect();
+        assert_eq!(got, vec![10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn test_node_splice_out_first() {
+        let mut root = node!(
+            1,
+            node!(2),
+            node!(3, node!(5), node!(6, node!(7))),
+            node!(4)
+        );
+
+        assert_eq!(root.splice_out_first(|value| *value == 3), Some(3));
+        assert_eq!(
+            root,
+            node!(1, node!(2), node!(5), node!(6, node!(7)), node!(4))
+        );
+
+        assert_eq!(root.splice_out_first(|value| *value == 1), None);
+        assert_eq!(root.splice_out_first(|value| *value == 8), None);
+    }
+
+    #[test]
+    fn test_node_count_edges() {
+        let root = node!(10, node!(20, node!(5)), node!(3, node!(4), node!(1)));
+        assert_eq!(root.count_edges(|parent, child| child > parent), 2);
+        assert_eq!(root.count_edges(|_, _| true), root.size());
+        assert_eq!(node!(1).count_edges(|_, _| true), 0);
+    }
+
+    #[test]
+    fn test_node_map_err() {
+        let root: Node<Result<i32, &str>> =
+            node!(Ok(1), node!(Err("a"), node!(Ok(3))), node!(Err("bc")));
+
+        let want: Node<Result<i32, usize>> =
+            node!(Ok(1), node!(Err(1), node!(Ok(3))), node!(Err(2)));
+        assert_eq!(root.map_err(str::len), want);
+    }
+
+    #[test]
+    fn test_node_subtree_frequencies() {
+        let root = node!(
+            1,
+            node!(2, node!(3), node!(4)),
+            node!(2, node!(3), node!(4)),
+            node!(3)
+        );
+
+        let frequencies = root.subtree_frequencies();
+        assert_eq!(frequencies.get("(3,4)2"), Some(&2));
+        assert_eq!(frequencies.get("3"), Some(&3));
+        assert_eq!(frequencies.get("4"), Some(&2));
+        assert_eq!(frequencies.get("((3,4)2,(3,4)2,3)1"), Some(&1));
+        assert_eq!(frequencies.len(), 4);
+
+        let root = node!("r", node!("(b)a"), node!("a", node!("b")));
+        let frequencies = root.subtree_frequencies();
+        assert_eq!(frequencies.get("'(b)a'"), Some(&1));
+        assert_eq!(frequencies.get("(b)a"), Some(&1));
+        assert_eq!(frequencies.len(), 4);
+    }
+
+    #[test]
+    fn test_node_summarize_breadth() {
+        let mut root = Node::new("root".to_string())
+            .with_children((0..10).map(|value| Node::new(value.to_string())).collect());
+
+        root.summarize_breadth(3, |remaining| format!("... and {remaining} more"));
+
+        let values: Vec<&str> = root.children.iter().map(|n| n.value.as_str()).collect();
+        assert_eq!(values, vec!["0", "1", "2", "... and 7 more"]);
+        assert!(root.children.iter().all(|n| n.children.is_empty()));
+
+        let mut root = node!(1, node!(2, node!(4), node!(5)), node!(3));
+        root.summarize_breadth(1, |remaining| remaining * 100);
+        assert_eq!(root, node!(1, node!(2, node!(4), node!(100)), node!(100)));
+    }
+
+    #[test]
+    fn test_node_ancestor_values() {
+        let root = node!(1, node!(2), node!(3, node!(4), node!(5, node!(6))));
+
+        assert_eq!(root.ancestor_values(&[1, 1]), Some(vec![&1, &3]));
+        assert_eq!(root.ancestor_values(&[1, 1, 0]), Some(vec![&1, &3, &5]));
+        assert_eq!(root.ancestor_values(&[0]), Some(vec![&1]));
+        assert_eq!(root.ancestor_values(&[]), Some(vec![]));
+        assert_eq!(root.ancestor_values(&[0, 0]), None);
+    }
+
+    #[test]
+    fn test_node_dedup_children_by_key() {
+        let mut root = node!(
+            1,
+            node!(2, node!(4), node!(5), node!(6), node!(7)),
+            node!(3),
+            node!(4),
+            node!(5)
+        );
+
+        root.dedup_children_by_key(|n| n.value % 2);
+        assert_eq!(root, node!(1, node!(2, node!(4), node!(5)), node!(3)));
+    }
+
+    #[test]
+    fn test_node_scan() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let sums = root.scan(0, |sum, n| {
+            *sum += n.value;
+            *sum
+        });
+
+        assert_eq!(sums, vec![10, 30, 70, 100, 150]);
+    }
+
+    #[test]
+    fn test_node_structural_hash() {
+        let root = node!(
+            1,
+            node!(2, node!(3)),
+            node!(2, node!(3)),
+            node!(2, node!(4))
+        );
+
+        assert_eq!(
+            root.children[0].structural_hash(),
+            root.children[1].structural_hash()
+        );
+        assert_ne!(
+            root.children[0].structural_hash(),
+            root.children[2].structural_hash()
+        );
+        assert_ne!(
+            node!(1, node!(2, node!(3))).structural_hash(),
+            node!(1, node!(2), node!(3)).structural_hash()
+        );
+    }
+
+    #[test]
+    fn test_node_map_ref_with_children() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
+
+        let got = root.map_ref_with_children(|value, children| value + children.len());
+        assert_eq!(
+            got,
+            node!(12, node!(21, node!(40)), node!(32, node!(50), node!(60)))
+        );
+        assert_eq!(root.value, 10);
+    }
+
+    #[test]
+    fn test_node_nodes_at_depth() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let values: Vec<i32> = root.nodes_at_depth(1).iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![20, 30]);
+
+        let values: Vec<i32> = root.nodes_at_depth(2).iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![40, 50]);
+
+        assert_eq!(root.nodes_at_depth(0), vec![&root]);
+        assert!(root.nodes_at_depth(3).is_empty());
+    }
+
+    #[test]
+    fn test_node_expand() {
+        let mut root = node!(1, node!(2, node!(4)), node!(3));
+
+        let added = root.expand(|n| {
+            if n.value % 2 == 0 {
+                vec![node!(n.value * 10)]
+            } else {
+                Vec::new()
+            }
+        });
+
+        assert_eq!(added, 2);
+        assert_eq!(
+            root,
+            node!(1, node!(2, node!(4, node!(40)), node!(20)), node!(3))
+        );
+    }
+
+    #[test]
+    fn test_node_into_leaf() {
+        let root = node!(10, node!(20, node!(40)), node!(30));
+        assert_eq!(root.into_leaf(), node!(10));
+    }
+
+    #[test]
+    fn test_node_make_leaf() {
+        let mut root = node!(10, node!(20, node!(40)), node!(30));
+        root.children[0].make_leaf();
+        assert_eq!(root, node!(10, node!(20), node!(30)));
+
+        root.make_leaf();
+        assert_eq!(root, node!(10));
+    }
+
+    #[test]
+    fn test_node_count_nodes_with_degree() {
+        let root = node!(
+            1,
+            node!(2, node!(4), node!(5)),
+            node!(3, node!(6, node!(7)))
+        );
+
+        assert_eq!(root.count_nodes_with_degree(0), 3);
+        assert_eq!(root.count_nodes_with_degree(1), 2);
+        assert_eq!(root.count_nodes_with_degree(2), 2);
+        assert_eq!(root.count_nodes_with_degree(3), 0);
+    }
+
+    #[test]
+    fn test_node_into_map_reduce() {
+        let root = node!(
+            "a".to_string(),
+            node!("bb".to_string(), node!("dddd".to_string())),
+            node!("ccc".to_string())
+        );
+
+        let total = root.into_map_reduce(
+            |value| value.len(),
+            |len, results| len + results.into_iter().sum::<usize>(),
+        );
+
+        assert_eq!(total, 10);
+    }
+
+    #[test]
+    fn test_node_reduce_bounded() {
+        let root = node!(
+            1,
+            node!(2, node!(4, node!(8))),
+            node!(3, node!(5), node!(6))
+        );
+
+        let got = root.reduce_bounded(
+            1,
+            |n, results: Vec<String>| format!("{}[{}]", n.value, results.join(",")),
+            |n| format!("{}+{}", n.value, n.size()),
+        );
+
+        assert_eq!(got, "1[2+2,3+2]");
+
+        let got = root.reduce_bounded(0, |_, _: Vec<usize>| 0, |n| n.size());
+        assert_eq!(got, root.size());
+    }
+
+    #[test]
+    fn test_node_preorder_values_rev() {
+        let root = node!(1, node!(2), node!(3));
+        assert_eq!(root.preorder_values_rev(), vec![&1, &3, &2]);
+
+        let pre: Vec<&i32> = root.traverse().pre().iter().map(|n| &n.value).collect();
+        assert_ne!(root.preorder_values_rev(), pre);
+
+        let root = node!(1, node!(2, node!(4), node!(5)), node!(3, node!(6)));
+        assert_eq!(root.preorder_values_rev(), vec![&1, &3, &6, &2, &5, &4]);
+    }
+
+    #[test]
+    fn test_node_to_bracketed() {
+        let root = node!(1, node!(2), node!(3));
+        assert_eq!(root.to_bracketed('[', ']', ','), "1[2,3]");
+
+        let root = node!(1, node!(2, node!(4), node!(5)), node!(3, node!(6)));
+        assert_eq!(root.to_bracketed('[', ']', ','), "1[2[4,5],3[6]]");
+        assert_eq!(root.to_bracketed('(', ')', ' '), "1(2(4 5) 3(6))");
+        assert_eq!(node!(1).to_bracketed('[', ']', ','), "1");
+    }
+
+    #[test]
+    fn test_node_with_degrees() {
+        let root = node!(10, node!(20, node!(40)), node!(30), node!(50));
+
+        let got = root.with_degrees();
+        assert_eq!(got.value, (root.children.len(), &10));
+        assert_eq!(
+            got,
+            node!(
+                (3, &10),
+                node!((1, &20), node!((0, &40))),
+                node!((0, &30)),
+                node!((0, &50))
+            )
+        );
+    }
+
+    #[test]
+    fn test_node_into_children() {
+        let root = node!(10, node!(20, node!(40)), node!(30));
+        assert_eq!(root.into_children(), vec![node!(20, node!(40)), node!(30)]);
+
+        let value = std::rc::Rc::new(1);
+        let root = node!(value.clone(), node!(value.clone()));
+        assert_eq!(std::rc::Rc::strong_count(&value), 3);
+
+        let children = root.into_children();
+        assert_eq!(std::rc::Rc::strong_count(&value), 2);
+        assert_eq!(children.len(), 1);
+    }
+
+    #[test]
+    fn test_node_find_all() {
+        let root = node!(
+            1,
+            node!(2, node!(4), node!(5)),
+            node!(3, node!(6, node!(7), node!(8)))
+        );
+
+        let found = root.find_all(|n| n.children.len() > 1);
+        let values: Vec<i32> = found.iter().map(|n| n.value).collect();
+        assert_eq!(found.len(), 3);
+        assert_eq!(values, vec![1, 2, 6]);
+
+        assert!(root.find_all(|n| n.value > 8).is_empty());
+    }
+
+    #[test]
+    fn test_node_map_with_level_info() {
+        let root = node!(10, node!(20, node!(40), node!(50)), node!(30, node!(60)));
+
+        let got = root.map_with_level_info(|depth, index, _| (depth, index));
+        assert_eq!(got.value, (0, 0));
+        assert_eq!(got.children[1].value, (1, 1));
+        assert_eq!(got.children[0].children[1].value, (2, 1));
+        assert_eq!(got.children[1].children[0].value, (2, 0));
+    }
+
+    #[test]
+    fn test_node_longest_branch() {
+        let root = node!(
+            1,
+            node!(2, node!(4)),
+            node!(3, node!(5, node!(7)), node!(6, node!(8)))
+        );
+
+        let branch = root.longest_branch();
+        assert_eq!(branch, vec![&1, &3, &5, &7]);
+        assert_eq!(branch.len(), root.height());
+
+        assert_eq!(node!(1).longest_branch(), vec![&1]);
+    }
+
+    #[test]
+    fn test_node_partition_children() {
+        let mut root = node!(1, node!(2, node!(3)), node!(5), node!(4), node!(7));
+
+        let removed = root.partition_children(|n| n.value % 2 == 0);
+        assert_eq!(root, node!(1, node!(2, node!(3)), node!(4)));
+        assert_eq!(removed, vec![node!(5), node!(7)]);
+    }
+
+    #[test]
+    fn test_node_keep_deepest_leaves() {
+        let root = node!(
+            1,
+            node!(2, node!(4, node!(8)), node!(5)),
+            node!(3, node!(6), node!(7, node!(9), node!(10, node!(11))))
+        );
+
+        let mut got = root.clone();
+        got.keep_deepest_leaves(2);
+        assert_eq!(
+            got,
+            node!(
+                1,
+                node!(2, node!(4, node!(8))),
+                node!(3, node!(7, node!(10, node!(11))))
+            )
+        );
+
+        let mut got = root.clone();
+        got.keep_deepest_leaves(1);
+        assert_eq!(got, node!(1, node!(3, node!(7, node!(10, node!(11))))));
+
+        let mut got = root.clone();
+        got.keep_deepest_leaves(0);
+        assert_eq!(got, node!(1));
+    }
+
+    #[test]
+    fn test_node_iter_postorder_with_depth() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let got: Vec<(usize, i32)> = root
+            .iter_postorder_with_depth()
+            .map(|(depth, n)| (depth, n.value))
+            .collect();
+
+        assert_eq!(got, vec![(2, 40), (1, 20), (2, 50), (1, 30), (0, 10)]);
+    }
+
+    #[test]
+    fn test_node_from_edges() {
+        let edges = [(0, 1), (0, 2), (1, 3), (2, 4), (1, 5)];
+        let got = Node::from_edges(10, &edges, vec![20, 30, 40, 50, 60]);
+        assert_eq!(
+            got,
+            Some(node!(
+                10,
+                node!(20, node!(40), node!(60)),
+                node!(30, node!(50))
+            ))
+        );
+
+        assert_eq!(Node::from_edges(10, &[], vec![]), Some(node!(10)));
+    }
+
+    #[test]
+    fn test_node_from_edges_round_trip() {
+        let root = node!(
+            1,
+            node!(2, node!(3), node!(4, node!(5))),
+            node!(6),
+            node!(7, node!(8))
+        );
+
+        let (values, edges) = root.to_adjacency_list();
+        let mut values = values.into_iter().copied();
+        let first = values.next().unwrap();
+        assert_eq!(
+            Node::from_edges(first, &edges, values.collect()),
+            Some(root)
+        );
+    }
+
+    #[test]
+    fn test_node_from_edges_malformed() {
+        // multiple parents
+        let edges = [(0, 1), (0, 2), (1, 3), (2, 3)];
+        assert_eq!(Node::from_edges(1, &edges, vec![2, 3, 4]), None);
+
+        // disconnected node
+        assert_eq!(Node::from_edges(1, &[(0, 1)], vec![2, 3]), None);
+
+        // cycle
+        let edges = [(0, 1), (2, 3), (3, 2)];
+        assert_eq!(Node::from_edges(1, &edges, vec![2, 3, 4]), None);
+
+        // root as a child
+        assert_eq!(Node::from_edges(1, &[(1, 0)], vec![2]), None);
+
+        // out of bounds
+        assert_eq!(Node::from_edges(1, &[(0, 2)], vec![2]), None);
+    }
+
+    #[test]
+    fn test_node_child_inversions() {
+        let root = node!(0, node!(3), node!(1), node!(2));
+        assert_eq!(root.child_inversions(|n| n.value), 2);
+
+        let root = node!(0, node!(3, node!(2), node!(1)), node!(1), node!(2));
+        assert_eq!(root.child_inversions(|n| n.value), 3);
+        assert_eq!(root.child_inversions(|n| -n.value), 1);
+
+        assert_eq!(
+            node!(1, node!(1), node!(1)).child_inversions(|n| n.value),
+            0
+        );
+    }
+
+    #[test]
+    fn test_node_render() {
+        let root = node!("*", node!("+", node!("1"), node!("2")), node!("3"));
+
+        let infix = root.render(|value, children| {
+            if children.is_empty() {
+                value.to_string()
+            } else {
+                format!("({})", children.join(&format!(" {value} ")))
+            }
+        });
+
+        assert_eq!(infix, "((1 + 2) * 3)");
+    }
+
+    #[test]
+    fn test_node_bfs_values_mut() {
+        let mut root = node!(0, node!(0, node!(0)), node!(0, node!(0), node!(0)));
+
+        let mut index = 0;
+        root.bfs_values_mut(|value| {
+            *value = index;
+            index += 1;
+        });
+
+        assert_eq!(
+            root,
+            node!(0, node!(1, node!(3)), node!(2, node!(4), node!(5)))
+        );
+    }
+
+    #[test]
+    fn test_node_dedup_identical_children() {
+        let mut root = node!(
+            1,
+            node!(2, node!(3), node!(4)),
+            node!(2, node!(3)),
+            node!(2, node!(3), node!(4)),
+            node!(2, node!(3), node!(3))
+        );
+
+        root.dedup_identical_children();
+        assert_eq!(
+            root,
+            node!(1, node!(2, node!(3), node!(4)), node!(2, node!(3)))
+        );
+    }
+
+    #[test]
+    fn test_node_fold_children() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+        assert_eq!(root.fold_children(0, |sum, n| sum + n.value), 50);
+        assert_eq!(node!(10).fold_children(0, |sum, n| sum + n.value), 0);
+    }
+
+    #[test]
+    fn test_node_debug_assert_all() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+        root.debug_assert_all(|n| n.value % 10 == 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "node at path [1, 0] with value 55")]
+    fn test_node_debug_assert_all_panics() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(55), node!(65)));
+        root.debug_assert_all(|n| n.value % 10 == 0);
+    }
+
+    #[test]
+    fn test_node_child_extremes_by_key() {
+        let root = node!(0, node!(3), node!(1), node!(2));
+
+        let (min, max) = root.child_extremes_by_key(|n| n.value).unwrap();
+        assert!(std::ptr::eq(min, &root.children[1]));
+        assert!(std::ptr::eq(max, &root.children[0]));
+
+        assert_eq!(node!(0).child_extremes_by_key(|n| n.value), None);
     }
 }
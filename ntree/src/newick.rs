@@ -0,0 +1,304 @@
+//! Conversion of [Node] from and into the Newick tree format.
+
+use crate::Node;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Characters having a special meaning in the Newick format, which cannot be part of a label.
+const RESERVED: &str = "(),:;";
+
+/// Returns the given value as a Newick label, enclosed in single quotes if it contains any reserved
+/// character, whitespace or quote, in which case any quote is doubled.
+pub(crate) fn newick_label<T: Display>(value: &T) -> String {
+    let label = value.to_string();
+    if label
+        .chars()
+        .any(|c| RESERVED.contains(c) || c.is_whitespace() || c == '\'')
+    {
+        format!("'{}'", label.replace('\'', "''"))
+    } else {
+        label
+    }
+}
+
+/// Represents the reasons why a string cannot be parsed as a Newick tree.
+#[derive(Debug, PartialEq, Eq)]
+pub enum NewickError {
+    /// The input ended before the tree was complete.
+    UnexpectedEnd,
+    /// The character at the given byte offset is not valid where it was found.
+    UnexpectedChar(usize, char),
+    /// The branch length starting at the given byte offset is not a number.
+    InvalidLength(usize),
+}
+
+impl Display for NewickError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NewickError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            NewickError::UnexpectedChar(offset, found) => {
+                write!(f, "unexpected character {found:?} at offset {offset}")
+            }
+            NewickError::InvalidLength(offset) => {
+                write!(f, "invalid branch length at offset {offset}")
+            }
+        }
+    }
+}
+
+impl Error for NewickError {}
+
+impl Node<String> {
+    /// Parses the given string as a tree in the Newick format, having the label of each node as its
+    /// value. Labels are optional, so unlabeled nodes get an empty string. Unquoted labels have their
+    /// surrounding whitespace trimmed, while labels enclosed in single quotes are kept verbatim, but
+    /// for any pair of consecutive quotes, which stands for a single one. Branch lengths, if any,
+    /// must be numbers, but they are discarded.
+    pub fn from_newick(s: &str) -> Result<Node<String>, NewickError> {
+        let mut parser = NewickParser {
+            input: s,
+            offset: 0,
+        };
+
+        let root = parser.subtree()?;
+        parser.expect(';')?;
+        parser.skip_whitespace();
+
+        match parser.peek() {
+            Some(found) => Err(NewickError::UnexpectedChar(parser.offset, found)),
+            None => Ok(root),
+        }
+    }
+}
+
+/// Recursive descent parser for the Newick format.
+struct NewickParser<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl NewickParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let next = self.peek()?;
+        self.offset += next.len_utf8();
+        Some(next)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), NewickError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(found) if found == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(found) => Err(NewickError::UnexpectedChar(self.offset, found)),
+            None => Err(NewickError::UnexpectedEnd),
+        }
+    }
+
+    /// Consumes all the characters up to the next reserved one, returning them trimmed.
+    fn token(&mut self) -> &str {
+        let start = self.offset;
+        while self.peek().is_some_and(|next| !RESERVED.contains(next)) {
+            self.bump();
+        }
+
+        self.input[start..self.offset].trim()
+    }
+
+    /// Consumes the label of a node, either quoted or not, returning it unescaped.
+    fn label(&mut self) -> Result<String, NewickError> {
+        self.skip_whitespace();
+        if self.peek() != Some('\'') {
+            return Ok(self.token().to_string());
+        }
+
+        self.bump();
+        let mut label = String::new();
+        loop {
+            match self.bump() {
+                Some('\'') if self.peek() == Some('\'') => {
+                    self.bump();
+                    label.push('\'');
+                }
+                Some('\'') => break,
+                Some(next) => label.push(next),
+                None => return Err(NewickError::UnexpectedEnd),
+            }
+        }
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some(found) if !RESERVED.contains(found) => {
+                Err(NewickError::UnexpectedChar(self.offset, found))
+            }
+            _ => Ok(label),
+        }
+    }
+
+    fn subtree(&mut self) -> Result<Node<String>, NewickError> {
+        self.skip_whitespace();
+
+        let mut children = Vec::new();
+        if self.peek() == Some('(') {
+            self.bump();
+            loop {
+                children.push(self.subtree()?);
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(',') => self.bump(),
+                    Some(')') => break,
+                    Some(found) => return Err(NewickError::UnexpectedChar(self.offset, found)),
+                    None => return Err(NewickError::UnexpectedEnd),
+                };
+            }
+
+            self.bump();
+        }
+
+        let label = self.label()?;
+        if self.peek() == Some(':') {
+            self.bump();
+            let start = self.offset;
+            self.token()
+                .parse::<f64>()
+                .map_err(|_| NewickError::InvalidLength(start))?;
+        }
+
+        Ok(Node::new(label).with_children(children))
+    }
+}
+
+impl<T: Display> Node<T> {
+    /// Returns the tree rooted by self in the Newick format. Children are enclosed in parentheses and
+    /// separated by commas, followed by the label of their parent, while leaves are just labels.
+    /// Labels containing reserved characters, whitespace or quotes are enclosed in single quotes. The
+    /// whole tree is terminated by a semicolon.
+    pub fn to_newick(&self) -> String {
+        fn to_newick_immersion<T: Display>(root: &Node<T>, newick: &mut String) {
+            if !root.children.is_empty() {
+                newick.push('(');
+                for (index, child) in root.children.iter().enumerate() {
+                    if index > 0 {
+                        newick.push(',');
+                    }
+
+                    to_newick_immersion(child, newick);
+                }
+
+                newick.push(')');
+            }
+
+            newick.push_str(&newick_label(&root.value));
+        }
+
+        let mut newick = String::new();
+        to_newick_immersion(self, &mut newick);
+        newick.push(';');
+        newick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node;
+
+    #[test]
+    fn test_to_newick() {
+        let root = node!(1, node!(2, node!(4), node!(5)), node!(3));
+        assert_eq!(root.to_newick(), "((4,5)2,3)1;");
+        assert_eq!(node!(1).to_newick(), "1;");
+
+        let root = node!("x y", node!("a,b"), node!("it's"));
+        assert_eq!(root.to_newick(), "('a,b','it''s')'x y';");
+    }
+
+    #[test]
+    fn test_newick_round_trip_quoted_labels() {
+        let root = node!(
+            "x y".to_string(),
+            node!("a,b".to_string(), node!("(c)".to_string())),
+            node!("it's;".to_string()),
+            node!(" d ".to_string())
+        );
+
+        assert_eq!(Node::from_newick(&root.to_newick()), Ok(root));
+    }
+
+    #[test]
+    fn test_from_newick() {
+        let root = node!(1, node!(2, node!(4), node!(5)), node!(3));
+        let parsed = Node::from_newick(&root.to_newick()).unwrap();
+
+        let want = root.traverse().map(|n| n.value.to_string()).take();
+        assert_eq!(parsed, want);
+        assert_eq!(parsed.to_newick(), root.to_newick());
+    }
+
+    #[test]
+    fn test_from_newick_with_lengths() {
+        let parsed = Node::from_newick("((a:1, b:2.5)c:0.5, (d, e));").unwrap();
+
+        let want = node!(
+            "".to_string(),
+            node!(
+                "c".to_string(),
+                node!("a".to_string()),
+                node!("b".to_string())
+            ),
+            node!(
+                "".to_string(),
+                node!("d".to_string()),
+                node!("e".to_string())
+            )
+        );
+
+        assert_eq!(parsed, want);
+    }
+
+    #[test]
+    fn test_from_newick_quoted_labels() {
+        let parsed = Node::from_newick("('a,b':1, 'it''s' , '' )' x y ';").unwrap();
+
+        let want = node!(
+            " x y ".to_string(),
+            node!("a,b".to_string()),
+            node!("it's".to_string()),
+            node!("".to_string())
+        );
+
+        assert_eq!(parsed, want);
+    }
+
+    #[test]
+    fn test_from_newick_errors() {
+        assert_eq!(Node::from_newick("(a,b"), Err(NewickError::UnexpectedEnd));
+        assert_eq!(Node::from_newick("(a,b)c"), Err(NewickError::UnexpectedEnd));
+        assert_eq!(
+            Node::from_newick("(a,b);c"),
+            Err(NewickError::UnexpectedChar(6, 'c'))
+        );
+        assert_eq!(
+            Node::from_newick("(a:x,b);"),
+            Err(NewickError::InvalidLength(3))
+        );
+        assert_eq!(Node::from_newick("('a;"), Err(NewickError::UnexpectedEnd));
+        assert_eq!(
+            Node::from_newick("'a'b;"),
+            Err(NewickError::UnexpectedChar(3, 'b'))
+        );
+    }
+}
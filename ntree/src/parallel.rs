@@ -0,0 +1,87 @@
+//! Parallel algorithms for [Node], backed by rayon.
+
+use crate::Node;
+use rayon::prelude::*;
+
+impl<T: Sync> Node<T> {
+    /// Calls the given closure for each node in the tree rooted by self, visiting sibling branches
+    /// in parallel. The order in which nodes are visited is unspecified.
+    pub fn par_for_each<F>(&self, f: F)
+    where
+        F: Fn(&Node<T>) + Sync + Send,
+    {
+        fn par_for_each_immersion<T, F>(root: &Node<T>, f: &F)
+        where
+            T: Sync,
+            F: Fn(&Node<T>) + Sync + Send,
+        {
+            rayon::join(
+                || f(root),
+                || {
+                    root.children
+                        .par_iter()
+                        .for_each(|child| par_for_each_immersion(child, f))
+                },
+            );
+        }
+
+        par_for_each_immersion(self, &f);
+    }
+}
+
+impl<T: Send> Node<T> {
+    /// Calls the given closure for each value in the tree rooted by self, transforming sibling
+    /// branches in parallel. The order in which values are visited is unspecified.
+    pub fn par_map_values<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send,
+    {
+        fn par_map_values_immersion<T, F>(root: &mut Node<T>, f: &F)
+        where
+            T: Send,
+            F: Fn(&mut T) + Sync + Send,
+        {
+            let Node { value, children } = root;
+            rayon::join(
+                || f(value),
+                || {
+                    children
+                        .par_iter_mut()
+                        .for_each(|child| par_map_values_immersion(child, f))
+                },
+            );
+        }
+
+        par_map_values_immersion(self, &f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_par_for_each() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
+
+        let result = Mutex::new(Vec::new());
+        root.par_for_each(|n| result.lock().unwrap().push(n.value));
+
+        let mut got = result.into_inner().unwrap();
+        got.sort();
+        assert_eq!(got, vec![10, 20, 30, 40, 50, 60]);
+    }
+
+    #[test]
+    fn test_par_map_values() {
+        let mut root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
+
+        let mut want = root.clone();
+        want.traverse_mut().for_each(|n| n.value *= n.value);
+
+        root.par_map_values(|value| *value *= *value);
+        assert_eq!(root, want);
+    }
+}
@@ -0,0 +1,134 @@
+//! Definition of trees whose subtrees are shared instead of owned.
+
+use crate::Node;
+use std::{collections::HashSet, hash::Hash, iter, rc::Rc, sync::Arc};
+
+/// Represents a node whose children are reference counted, so identical subtrees can be stored
+/// just once and shared among all the parents holding them.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct SharedNode<T> {
+    pub value: T,
+    pub children: Vec<Rc<SharedNode<T>>>,
+}
+
+impl<T: Clone> SharedNode<T> {
+    /// Returns the [Node] resulting from expanding all the shared subtrees of self.
+    pub fn to_node(&self) -> Node<T> {
+        Node::new(self.value.clone())
+            .with_children(self.children.iter().map(|child| child.to_node()).collect())
+    }
+}
+
+impl<T: Hash + Eq> Node<T> {
+    /// Consumes the tree rooted by self, returning the equivalent [SharedNode] in which all the
+    /// identical subtrees, by value and shape, are stored just once.
+    pub fn dedup_into_shared(self) -> SharedNode<T> {
+        fn dedup_immersion<T: Hash + Eq>(
+            root: Node<T>,
+            seen: &mut HashSet<Rc<SharedNode<T>>>,
+        ) -> SharedNode<T> {
+            let children = root
+                .children
+                .into_iter()
+                .map(|child| {
+                    let child = dedup_immersion(child, seen);
+                    if let Some(shared) = seen.get(&child) {
+                        return shared.clone();
+                    }
+
+                    let shared = Rc::new(child);
+                    seen.insert(shared.clone());
+                    shared
+                })
+                .collect();
+
+            SharedNode {
+                value: root.value,
+                children,
+            }
+        }
+
+        dedup_immersion(self, &mut HashSet::new())
+    }
+}
+
+/// Represents an immutable tree whose children are atomically reference counted, so it can be
+/// shared among threads without cloning it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SharedTree<T> {
+    pub value: T,
+    pub children: Vec<Arc<SharedTree<T>>>,
+}
+
+impl<T> SharedTree<T> {
+    /// Returns an iterator over the nodes of the tree rooted by self in `pre-order`.
+    pub fn pre(&self) -> impl Iterator<Item = &SharedTree<T>> {
+        let mut next = vec![self];
+        iter::from_fn(move || {
+            let current = next.pop()?;
+            next.extend(current.children.iter().rev().map(Arc::as_ref));
+            Some(current)
+        })
+    }
+
+    /// Returns an iterator over the nodes of the tree rooted by self in `post-order`.
+    pub fn post(&self) -> impl Iterator<Item = &SharedTree<T>> {
+        let mut stack = vec![(self, 0)];
+        iter::from_fn(move || loop {
+            let (current, next_child) = stack.last_mut()?;
+            if let Some(child) = current.children.get(*next_child) {
+                *next_child += 1;
+                stack.push((child, 0));
+            } else {
+                return stack.pop().map(|(current, _)| current);
+            }
+        })
+    }
+}
+
+impl<T> Node<T> {
+    /// Consumes the tree rooted by self, returning the equivalent [SharedTree].
+    pub fn into_shared(self) -> Arc<SharedTree<T>> {
+        Arc::new(SharedTree {
+            value: self.value,
+            children: self.children.into_iter().map(Node::into_shared).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node;
+    use std::thread;
+
+    #[test]
+    fn test_dedup_into_shared() {
+        let root = node!(1, node!(2, node!(3)), node!(2, node!(3)), node!(4));
+        let shared = root.clone().dedup_into_shared();
+
+        assert!(Rc::ptr_eq(&shared.children[0], &shared.children[1]));
+        assert_eq!(Rc::strong_count(&shared.children[0]), 2);
+        assert_eq!(Rc::strong_count(&shared.children[2]), 1);
+        assert_eq!(shared.to_node(), root);
+    }
+
+    #[test]
+    fn test_into_shared() {
+        let shared = node!(10, node!(20, node!(40)), node!(30, node!(50))).into_shared();
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || shared.pre().map(|n| n.value).collect::<Vec<_>>())
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), vec![10, 20, 40, 30, 50]);
+        }
+
+        let post: Vec<i32> = shared.post().map(|n| n.value).collect();
+        assert_eq!(post, vec![40, 20, 50, 30, 10]);
+    }
+}
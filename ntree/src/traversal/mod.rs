@@ -1,3 +1,5 @@
+#[cfg(feature = "async")]
+use crate::Asynchronous;
 use crate::{Node, Synchronous};
 
 mod traverse;
@@ -29,3 +31,88 @@ impl<'a, T> Node<T> {
         self.into()
     }
 }
+
+#[cfg(feature = "async")]
+impl<'a, T: Sync + Send + 'a> Node<T> {
+    /// Returns an asynchronous instance of [Traverse] for the given reference of node.
+    pub fn traverse_async(&'a self) -> Traverse<'a, T, Asynchronous> {
+        Traverse::new_async(self)
+    }
+
+    /// Returns an asynchronous instance of [TraverseMut] for the given mutable reference of node.
+    pub fn traverse_mut_async(&'a mut self) -> TraverseMut<'a, T, Asynchronous> {
+        TraverseMut::new_async(self)
+    }
+
+    /// Returns an asynchronous instance of [TraverseOwned] owning the given instance of node.
+    pub fn into_traverse_async(self) -> TraverseOwned<T, Asynchronous> {
+        TraverseOwned::new_async(self)
+    }
+
+    /// Maps the value of each node in the tree rooted by self with the first closure, combining it
+    /// with the results of its children, which are computed concurrently, using the second one.
+    pub async fn map_reduce<M, R, FM, FR>(&'a self, map: FM, reduce: FR) -> R
+    where
+        FM: Fn(&T) -> M + Sync + Send,
+        FR: Fn(M, Vec<R>) -> R + Sync + Send,
+        R: Sized + Sync + Send,
+    {
+        self.traverse_async().map_reduce(map, reduce).await
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+    use crate::node;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn test_traverse_async() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let result = Arc::new(Mutex::new(Vec::new()));
+        root.traverse_async()
+            .for_each(|n| result.lock().unwrap().push(n.value))
+            .await;
+
+        let mut got = result.lock().unwrap().clone();
+        got.sort();
+        assert_eq!(got, vec![10, 20, 30, 40, 50]);
+    }
+
+    #[tokio::test]
+    async fn test_traverse_mut_async() {
+        let mut root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        root.traverse_mut_async().for_each(|n| n.value += 1).await;
+
+        assert_eq!(root, node!(11, node!(21, node!(41)), node!(31, node!(51))));
+    }
+
+    #[tokio::test]
+    async fn test_into_traverse_async() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let result = Arc::new(Mutex::new(Vec::new()));
+        root.into_traverse_async()
+            .for_each(|value| result.lock().unwrap().push(value))
+            .await;
+
+        let mut got = result.lock().unwrap().clone();
+        got.sort();
+        assert_eq!(got, vec![10, 20, 30, 40, 50]);
+    }
+
+    #[tokio::test]
+    async fn test_map_reduce() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let map = |value: &i32| value * 2;
+        let reduce = |value: i32, results: Vec<i32>| value + results.iter().sum::<i32>();
+
+        let sum = root.map_reduce(map, reduce).await;
+        assert_eq!(sum, 300);
+        assert_eq!(sum, root.traverse().map_reduce(map, reduce));
+    }
+}
@@ -36,6 +36,34 @@ impl<'a, T: Sync + Send + 'a> Traverse<'a, T, Asynchronous> {
     macros_async::map!(&Node<T>, iter);
     macros_async::reduce!(&Node<T>, iter);
     macros_async::cascade!(&Node<T>, iter);
+
+    #[async_recursion]
+    async fn map_reduce_immersion<M, R, FM, FR>(root: &Node<T>, map: &FM, reduce: &FR) -> R
+    where
+        FM: Fn(&T) -> M + Sync + Send,
+        FR: Fn(M, Vec<R>) -> R + Sync + Send,
+        R: Sized + Sync + Send,
+    {
+        let results = join_all(
+            root.children
+                .iter()
+                .map(|child| Self::map_reduce_immersion(child, map, reduce)),
+        )
+        .await;
+
+        reduce(map(&root.value), results)
+    }
+
+    /// Maps the value of each node in the tree rooted by self with the first closure, combining it
+    /// with the results of its children, which are computed concurrently, using the second one.
+    pub async fn map_reduce<M, R, FM, FR>(self, map: FM, reduce: FR) -> R
+    where
+        FM: Fn(&T) -> M + Sync + Send,
+        FR: Fn(M, Vec<R>) -> R + Sync + Send,
+        R: Sized + Sync + Send,
+    {
+        Self::map_reduce_immersion(self.node, &map, &reduce).await
+    }
 }
 
 #[cfg(test)]
@@ -98,6 +126,18 @@ mod tests {
         assert_eq!(got[got.len() - 1], 10);
     }
 
+    #[tokio::test]
+    async fn test_map_reduce() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let map = |value: &i32| value * 2;
+        let reduce = |value: i32, results: Vec<i32>| value + results.iter().sum::<i32>();
+
+        let sum = root.traverse().into_async().map_reduce(map, reduce).await;
+        assert_eq!(sum, 300);
+        assert_eq!(sum, root.traverse().map_reduce(map, reduce));
+    }
+
     #[tokio::test]
     async fn test_cascade() {
         let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
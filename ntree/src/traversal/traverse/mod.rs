@@ -2,14 +2,10 @@
 
 #[cfg(feature = "async")]
 mod r#async;
-#[cfg(feature = "async")]
-pub use r#async::*;
-
 mod sync;
-pub use sync::*;
 
 use crate::Node;
-use std::marker::PhantomData;
+use std::{collections::VecDeque, iter, marker::PhantomData};
 
 /// Implements the traverse algorithms for an immutable reference of a [`Node`].
 pub struct Traverse<'a, T, S> {
@@ -35,7 +31,7 @@ impl<'a, T, S> Traverse<'a, T, S> {
     pub fn pre(self) -> InPre<'a, T, S> {
         InPre {
             node: self.node,
-            next: vec![self.node],
+            next: vec![(0, self.node)],
             strategy: PhantomData,
         }
     }
@@ -48,12 +44,20 @@ impl<'a, T, S> Traverse<'a, T, S> {
             strategy: PhantomData,
         }
     }
+
+    /// Returns the `level-order` traversal entity for the tree.
+    pub fn level(self) -> InLevel<'a, T, S> {
+        InLevel {
+            next: VecDeque::from([self.node]),
+            strategy: PhantomData,
+        }
+    }
 }
 
 /// Represents the `pre-order` traversal.
 pub struct InPre<'a, T, S> {
     node: &'a Node<T>,
-    next: Vec<&'a Node<T>>,
+    next: Vec<(usize, &'a Node<T>)>,
     strategy: PhantomData<S>,
 }
 
@@ -61,9 +65,7 @@ impl<'a, T, S> Iterator for InPre<'a, T, S> {
     type Item = &'a Node<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let current = self.next.pop()?;
-        self.next.extend(current.children.iter().rev());
-        Some(current)
+        self.next_with_depth().map(|(_, current)| current)
     }
 }
 
@@ -71,6 +73,25 @@ impl<'a, T, S> InPre<'a, T, S> {
     pub fn iter(self) -> impl Iterator<Item = &'a Node<T>> {
         self
     }
+
+    /// Returns an iterator over the remaining nodes of the traversal, each paired with its depth,
+    /// being 0 the depth of the root.
+    pub fn with_depth(mut self) -> impl Iterator<Item = (usize, &'a Node<T>)> {
+        iter::from_fn(move || self.next_with_depth())
+    }
+
+    fn next_with_depth(&mut self) -> Option<(usize, &'a Node<T>)> {
+        let (depth, current) = self.next.pop()?;
+        self.next.extend(
+            current
+                .children
+                .iter()
+                .rev()
+                .map(|child| (depth + 1, child)),
+        );
+
+        Some((depth, current))
+    }
 }
 
 /// Represents the `post-order` traversal.
@@ -101,6 +122,28 @@ impl<'a, T, S> InPost<'a, T, S> {
     }
 }
 
+/// Represents the `level-order` traversal.
+pub struct InLevel<'a, T, S> {
+    next: VecDeque<&'a Node<T>>,
+    strategy: PhantomData<S>,
+}
+
+impl<'a, T, S> Iterator for InLevel<'a, T, S> {
+    type Item = &'a Node<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.pop_front()?;
+        self.next.extend(current.children.iter());
+        Some(current)
+    }
+}
+
+impl<'a, T, S> InLevel<'a, T, S> {
+    pub fn iter(self) -> impl Iterator<Item = &'a Node<T>> {
+        self
+    }
+}
+
 /// Implements both traversals at once.
 pub struct PrePost<'a, T, R, F, S> {
     node: &'a Node<T>,
@@ -147,4 +190,42 @@ mod tests {
 
         assert_eq!(result, vec![40, 50, 60, 20, 70, 80, 30, 10]);
     }
+
+    #[test]
+    fn test_level_order_traversal() {
+        let root = node!(
+            10,
+            node!(20, node!(40), node!(50), node!(60)),
+            node!(30, node!(70), node!(80))
+        );
+
+        let mut result = Vec::new();
+        root.traverse()
+            .level()
+            .iter()
+            .for_each(|n| result.push(n.value));
+
+        assert_eq!(result, vec![10, 20, 30, 40, 50, 60, 70, 80]);
+
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+        let result: Vec<i32> = root.traverse().level().map(|n| n.value).collect();
+        assert_eq!(result, vec![10, 20, 30, 40, 50]);
+    }
+
+    #[test]
+    fn test_pre_order_with_depth() {
+        let root = node!(10, node!(20, node!(40), node!(50)), node!(30, node!(60)));
+
+        let result: Vec<(usize, i32)> = root
+            .traverse()
+            .pre()
+            .with_depth()
+            .map(|(depth, n)| (depth, n.value))
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![(0, 10), (1, 20), (2, 40), (2, 50), (1, 30), (2, 60)]
+        );
+    }
 }
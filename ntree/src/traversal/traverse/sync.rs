@@ -33,11 +33,135 @@ impl<'a, T> Traverse<'a, T, Synchronous> {
     macros::map!(&Node<T>, iter);
     macros::reduce!(&Node<T>, iter);
     macros::cascade!(&Node<T>, iter);
+
+    /// Traverses the tree rooted by self in `post-order`, mapping the value of each node with the first
+    /// closure and combining it with the results of its children using the second one.
+    pub fn map_reduce<M, R, FM, FR>(self, mut map: FM, mut reduce: FR) -> R
+    where
+        FM: FnMut(&T) -> M,
+        FR: FnMut(M, Vec<R>) -> R,
+    {
+        fn map_reduce_immersion<T, M, R, FM, FR>(root: &Node<T>, map: &mut FM, reduce: &mut FR) -> R
+        where
+            FM: FnMut(&T) -> M,
+            FR: FnMut(M, Vec<R>) -> R,
+        {
+            let results = root
+                .children
+                .iter()
+                .map(|child| map_reduce_immersion(child, map, reduce))
+                .collect();
+
+            reduce(map(&root.value), results)
+        }
+
+        map_reduce_immersion(self.node, &mut map, &mut reduce)
+    }
+
+    /// Traverses the tree rooted by self in `post-order`, calling the given closure along the way and
+    /// providing its results from children to parent. The whole reduction is aborted as soon as the
+    /// closure returns [None].
+    pub fn reduce_while<R, F>(&self, mut f: F) -> Option<R>
+    where
+        F: FnMut(&Node<T>, Vec<R>) -> Option<R>,
+    {
+        fn reduce_while_immersion<T, R, F>(root: &Node<T>, f: &mut F) -> Option<R>
+        where
+            F: FnMut(&Node<T>, Vec<R>) -> Option<R>,
+        {
+            let results = root
+                .children
+                .iter()
+                .map(|child| reduce_while_immersion(child, f))
+                .collect::<Option<Vec<R>>>()?;
+
+            f(root, results)
+        }
+
+        reduce_while_immersion(self.node, &mut f)
+    }
+
+    /// Traverses the tree rooted by self in `pre-order`, folding every node into the accumulator by
+    /// calling the given closure. The traversal is aborted as soon as the accumulator satisfies the
+    /// stop condition, which is checked before visiting each node.
+    pub fn reduce_until<R, F, S>(&self, init: R, mut f: F, stop: S) -> R
+    where
+        F: FnMut(R, &Node<T>) -> R,
+        S: Fn(&R) -> bool,
+    {
+        let mut acc = init;
+        let mut next = vec![self.node];
+        while !stop(&acc) {
+            let Some(node) = next.pop() else {
+                break;
+            };
+
+            acc = f(acc, node);
+            next.extend(node.children.iter().rev());
+        }
+
+        acc
+    }
+
+    /// Traverses the tree rooted by self in `post-order`, calling the given closure along the way and
+    /// providing the results of the children of each node. Returns the results of all the nodes, in
+    /// `post-order`, so the one of the root is the last.
+    pub fn reduce_all<R, F>(&self, mut f: F) -> Vec<R>
+    where
+        F: FnMut(&Node<T>, &[R]) -> R,
+        R: Clone,
+    {
+        fn reduce_all_immersion<T, R, F>(root: &Node<T>, f: &mut F, all: &mut Vec<R>) -> R
+        where
+            F: FnMut(&Node<T>, &[R]) -> R,
+            R: Clone,
+        {
+            let results: Vec<R> = root
+                .children
+                .iter()
+                .map(|child| reduce_all_immersion(child, f, all))
+                .collect();
+
+            let result = f(root, &results);
+            all.push(result.clone());
+            result
+        }
+
+        let mut all = Vec::new();
+        reduce_all_immersion(self.node, &mut f, &mut all);
+        all
+    }
 }
 
 impl<'a, T> InPre<'a, T, Synchronous> {
     macros::cascade!(&Node<T>, iter);
     macros::map_pre!(&Node<T>, iter);
+
+    /// Traverses the tree in `pre-order`, building a new tree by calling the given closure along the way
+    /// while threading the given accumulator through it. Returns both the new tree and the final state
+    /// of the accumulator.
+    pub fn map_scan<A, R, F>(self, init: A, mut f: F) -> (Node<R>, A)
+    where
+        F: FnMut(&mut A, &Node<T>) -> R,
+    {
+        fn map_scan_immersion<T, A, R, F>(root: &Node<T>, acc: &mut A, f: &mut F) -> Node<R>
+        where
+            F: FnMut(&mut A, &Node<T>) -> R,
+        {
+            let parent = Node::new(f(acc, root));
+            let children: Vec<Node<R>> = root
+                .children
+                .iter()
+                .map(|node| map_scan_immersion(node, acc, f))
+                .collect();
+
+            parent.with_children(children)
+        }
+
+        let mut acc = init;
+        let root = map_scan_immersion(self.node, &mut acc, &mut f);
+        (root, acc)
+    }
 }
 
 impl<'a, T> InPost<'a, T, Synchronous> {
@@ -103,6 +227,75 @@ mod tests {
         assert_eq!(sum, 150);
     }
 
+    #[test]
+    fn test_map_reduce() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let sum = root.traverse().map_reduce(
+            |value| value * 2,
+            |value, results| value + results.iter().sum::<i32>(),
+        );
+
+        assert_eq!(sum, 300);
+    }
+
+    #[test]
+    fn test_reduce_while() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let mut visited = Vec::new();
+        let sum = root.traverse().reduce_while(|n, results| {
+            visited.push(n.value);
+            (n.value != 40).then(|| n.value + results.iter().sum::<i32>())
+        });
+
+        assert_eq!(sum, None);
+        assert_eq!(visited, vec![40]);
+
+        let sum = root
+            .traverse()
+            .reduce_while(|n, results| Some(n.value + results.iter().sum::<i32>()));
+
+        assert_eq!(sum, Some(150));
+    }
+
+    #[test]
+    fn test_reduce_until() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let mut visited = Vec::new();
+        let sum = root.traverse().reduce_until(
+            0,
+            |sum, n| {
+                visited.push(n.value);
+                sum + n.value
+            },
+            |sum| *sum > 50,
+        );
+
+        assert_eq!(sum, 70);
+        assert_eq!(visited, vec![10, 20, 40]);
+
+        let sum = root
+            .traverse()
+            .reduce_until(0, |sum, n| sum + n.value, |_| false);
+
+        assert_eq!(sum, 150);
+    }
+
+    #[test]
+    fn test_reduce_all() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50), node!(60)));
+
+        let sizes = root
+            .traverse()
+            .reduce_all(|n, results: &[usize]| n.children.len() + results.iter().sum::<usize>());
+
+        let want: Vec<usize> = root.traverse().post().iter().map(Node::size).collect();
+        assert_eq!(sizes, want);
+        assert_eq!(sizes.last(), Some(&root.size()));
+    }
+
     #[test]
     fn test_cascade() {
         let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
@@ -208,4 +401,19 @@ mod tests {
         let want = node!(false, node!(false, node!(true)), node!(true, node!(false)));
         assert_eq!(new_root, want);
     }
+
+    #[test]
+    fn test_map_scan_pre() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let (new_root, count) = root.traverse().pre().map_scan(0, |count, _| {
+            *count += 1;
+            *count
+        });
+
+        assert_eq!(count, 5);
+
+        let want = node!(1, node!(2, node!(3)), node!(4, node!(5)));
+        assert_eq!(new_root, want);
+    }
 }
@@ -2,14 +2,10 @@
 
 #[cfg(feature = "async")]
 mod r#async;
-#[cfg(feature = "async")]
-pub use r#async::*;
-
 mod sync;
-pub use sync::*;
 
 use crate::{Asynchronous, Node, Synchronous};
-use std::marker::PhantomData;
+use std::{iter, marker::PhantomData};
 
 /// Implements the traverse algorithms for a mutable reference of a [`Node`].
 pub struct TraverseMut<'a, T, S> {
@@ -72,6 +68,20 @@ pub struct InPreMut<'a, T, S> {
     strategy: PhantomData<S>,
 }
 
+impl<'a, T, S> InPreMut<'a, T, S> {
+    /// Returns an iterator over the values of the tree in `pre-order`, each paired with the depth of
+    /// its node, being 0 the depth of the root. Values are yielded instead of nodes, since a node
+    /// cannot be borrowed mutably while its children are still pending to be visited.
+    pub fn with_depth(self) -> impl Iterator<Item = (usize, &'a mut T)> {
+        let mut next = vec![(0, self.node)];
+        iter::from_fn(move || {
+            let (depth, Node { value, children }) = next.pop()?;
+            next.extend(children.iter_mut().rev().map(|child| (depth + 1, child)));
+            Some((depth, value))
+        })
+    }
+}
+
 /// Represents the `post-order` traversal.
 pub struct InPostMut<'a, T, S> {
     node: &'a mut Node<T>,
@@ -85,3 +95,33 @@ pub struct PrePostMut<'a, T, R, F, S> {
     r: PhantomData<R>,
     strategy: PhantomData<S>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node;
+
+    #[test]
+    fn test_pre_order_with_depth() {
+        let mut root = node!(10, node!(20, node!(40), node!(50)), node!(30, node!(60)));
+
+        let result: Vec<(usize, i32)> = root
+            .traverse_mut()
+            .pre()
+            .with_depth()
+            .map(|(depth, value)| {
+                *value += 1;
+                (depth, *value)
+            })
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![(0, 11), (1, 21), (2, 41), (2, 51), (1, 31), (2, 61)]
+        );
+        assert_eq!(
+            root,
+            node!(11, node!(21, node!(41), node!(51)), node!(31, node!(61)))
+        );
+    }
+}
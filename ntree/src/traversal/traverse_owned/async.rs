@@ -1,7 +1,10 @@
 //! Asynchronous implementation of both, the [`Traverser`] and [`TraverserMut`].
 
 use async_recursion::async_recursion;
-use futures::future::join_all;
+use futures::{
+    future::{join_all, ready},
+    stream, Future, StreamExt,
+};
 
 use crate::{traversal::TraverseOwned, Asynchronous, Node, Synchronous, TraverseMut};
 
@@ -97,6 +100,45 @@ impl<T: Sync + Send> TraverseOwned<T, Asynchronous> {
         Self::reduce_immersion(self.node, &f).await
     }
 
+    #[async_recursion]
+    async fn reduce_buffered_immersion<F, Fut, R>(root: Node<T>, limit: usize, f: &F) -> R
+    where
+        T: 'async_recursion,
+        F: Fn(T, Vec<R>) -> Fut + Sync + Send,
+        Fut: Future<Output = R> + Send,
+        R: Sized + Sync + Send,
+    {
+        let mut results: Vec<(usize, R)> = stream::iter(root.children.into_iter().enumerate())
+            .map(|(index, child)| async move {
+                (
+                    index,
+                    Self::reduce_buffered_immersion(child, limit, f).await,
+                )
+            })
+            .buffer_unordered(limit)
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _)| *index);
+        f(
+            root.value,
+            results.into_iter().map(|(_, result)| result).collect(),
+        )
+        .await
+    }
+
+    /// Traverses the tree rooted by self in `post-order`, calling the given closure along the way and providing its results from children to parent.
+    /// Unlike [TraverseOwned::reduce], at most `limit` children of each node are reduced concurrently, being 1 the lowest limit possible.
+    /// The results of the children are provided in the same order as the children themselves.
+    pub async fn reduce_buffered<F, R>(self, limit: usize, f: F) -> R
+    where
+        F: Fn(T, Vec<R>) -> R + Sync + Send,
+        R: Sized + Sync + Send,
+    {
+        let f = |value, results| ready(f(value, results));
+        Self::reduce_buffered_immersion(self.node, limit.max(1), &f).await
+    }
+
     /// Traverses the tree rooted by self in `pre-order`, calling the given closure along the way and providing its result from parent to children.
     pub async fn cascade<F, R>(mut self, base: R, f: F) -> Self
     where
@@ -112,7 +154,10 @@ impl<T: Sync + Send> TraverseOwned<T, Asynchronous> {
 mod tests {
     use super::*;
     use crate::node;
-    use std::sync::{Arc, Mutex};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
 
     #[tokio::test]
     async fn test_for_each() {
@@ -159,6 +204,50 @@ mod tests {
         assert_eq!(sum, 19);
     }
 
+    #[tokio::test]
+    async fn test_reduce_buffered() {
+        let root = Node::new(0).with_children(
+            (1..=10)
+                .map(|value| node!(value, node!(value * 10), node!(value * 100)))
+                .collect(),
+        );
+
+        let f = |value: i32, results: Vec<Vec<i32>>| {
+            let mut values = results.concat();
+            values.push(value);
+            values
+        };
+
+        let want = root.clone().into_traverse().into_async().reduce(f).await;
+        let got = root
+            .into_traverse()
+            .into_async()
+            .reduce_buffered(2, f)
+            .await;
+
+        assert_eq!(got, want);
+    }
+
+    #[tokio::test]
+    async fn test_reduce_buffered_limit() {
+        let root = Node::new(0).with_children((1..=10).map(Node::new).collect());
+
+        let in_flight = &AtomicUsize::new(0);
+        let peak = &AtomicUsize::new(0);
+        let f = move |value: i32, results: Vec<i32>| async move {
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(current, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            value + results.into_iter().sum::<i32>()
+        };
+
+        let got = TraverseOwned::<i32, Asynchronous>::reduce_buffered_immersion(root, 2, &f).await;
+
+        assert_eq!(got, 55);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
     #[tokio::test]
     async fn test_cascade() {
         let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
@@ -2,14 +2,10 @@
 
 #[cfg(feature = "async")]
 mod r#async;
-#[cfg(feature = "async")]
-pub use r#async::*;
-
 mod sync;
-pub use sync::*;
 
 use crate::{Asynchronous, Node, Synchronous};
-use std::{marker::PhantomData, ops::Not};
+use std::{collections::VecDeque, iter, marker::PhantomData, ops::Not};
 
 /// Implements the traverse algorithms for an owned instance of [`Node`].
 pub struct TraverseOwned<T, S> {
@@ -57,7 +53,7 @@ impl<T, S> TraverseOwned<T, S> {
     /// Returns the `pre-order` traversal entity for the tree.
     pub fn pre(self) -> InPreOwned<T, S> {
         InPreOwned {
-            next: vec![self.node],
+            next: vec![(0, self.node)],
             strategy: PhantomData,
         }
     }
@@ -69,11 +65,32 @@ impl<T, S> TraverseOwned<T, S> {
             strategy: PhantomData,
         }
     }
+
+    /// Returns an iterator over the nodes of the tree in `pre-order`, consuming it. Each node is
+    /// yielded detached from its children, which come right after it, so every item is a leaf.
+    pub fn pre_nodes(self) -> impl Iterator<Item = Node<T>> {
+        self.pre().map(Node::new)
+    }
+
+    /// Returns an iterator over the values of the tree in `post-order`, consuming it.
+    pub fn post_values(self) -> impl Iterator<Item = T> {
+        self.post().map(|node| node.value)
+    }
+
+    /// Returns an iterator over the values of the tree in breadth-first order, consuming it.
+    pub fn bfs(self) -> impl Iterator<Item = T> {
+        let mut queue = VecDeque::from([self.node]);
+        iter::from_fn(move || {
+            let node = queue.pop_front()?;
+            queue.extend(node.children);
+            Some(node.value)
+        })
+    }
 }
 
 /// Represents the `pre-order` traversal.
 pub struct InPreOwned<T, S> {
-    next: Vec<Node<T>>,
+    next: Vec<(usize, Node<T>)>,
     strategy: PhantomData<S>,
 }
 
@@ -81,9 +98,28 @@ impl<T, S> Iterator for InPreOwned<T, S> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let current = self.next.pop()?;
-        self.next.extend(current.children.into_iter().rev());
-        Some(current.value)
+        self.next_with_depth().map(|(_, value)| value)
+    }
+}
+
+impl<T, S> InPreOwned<T, S> {
+    /// Returns an iterator over the remaining values of the traversal, each paired with the depth of
+    /// its node, being 0 the depth of the root.
+    pub fn with_depth(mut self) -> impl Iterator<Item = (usize, T)> {
+        iter::from_fn(move || self.next_with_depth())
+    }
+
+    fn next_with_depth(&mut self) -> Option<(usize, T)> {
+        let (depth, current) = self.next.pop()?;
+        self.next.extend(
+            current
+                .children
+                .into_iter()
+                .rev()
+                .map(|child| (depth + 1, child)),
+        );
+
+        Some((depth, current.value))
     }
 }
 
@@ -157,4 +193,42 @@ mod tests {
 
         assert_eq!(result, vec![40, 50, 60, 20, 70, 80, 30, 10]);
     }
+
+    #[test]
+    fn test_pre_nodes() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let result: Vec<Node<i32>> = root.into_traverse().pre_nodes().collect();
+        assert_eq!(
+            result,
+            vec![node!(10), node!(20), node!(40), node!(30), node!(50)]
+        );
+    }
+
+    #[test]
+    fn test_bfs() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let result: Vec<i32> = root.into_traverse().bfs().collect();
+        assert_eq!(result, vec![10, 20, 30, 40, 50]);
+    }
+
+    #[test]
+    fn test_post_values() {
+        let root = node!(10, node!(20, node!(40)), node!(30, node!(50)));
+
+        let result: Vec<i32> = root.into_traverse().post_values().collect();
+        assert_eq!(result, vec![40, 20, 50, 30, 10]);
+    }
+
+    #[test]
+    fn test_pre_order_with_depth() {
+        let root = node!(10, node!(20, node!(40), node!(50)), node!(30, node!(60)));
+
+        let result: Vec<(usize, i32)> = root.into_traverse().pre().with_depth().collect();
+        assert_eq!(
+            result,
+            vec![(0, 10), (1, 20), (2, 40), (2, 50), (1, 30), (2, 60)]
+        );
+    }
 }
@@ -115,7 +115,7 @@ impl<T> InPreOwned<T, Synchronous> {
             parent.with_children(children)
         }
 
-        map_immersion(self.next.remove(0), &base, &mut pre)
+        map_immersion(self.next.remove(0).1, &base, &mut pre)
     }
 
     /// Traverses the tree rooted by self in `pre-order`, calling the given closure along the way and providing its result from parent to children.
@@ -133,7 +133,7 @@ impl<T> InPreOwned<T, Synchronous> {
                 .for_each(|child| cascade_immersion(child, &base, f));
         }
 
-        cascade_immersion(self.next.remove(0), &base, &mut f);
+        cascade_immersion(self.next.remove(0).1, &base, &mut f);
         self
     }
 }